@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ezcgmath::prelude::*;
+
+fn view_matrix() -> Matrix4x4 {
+    Matrix4x4::from(Quaternion::from_euler(Degrees(30.0), Degrees(-50.0), Degrees(110.0)))
+        * Matrix4x4::from_translation(&Vector3::new(120.0, -35.5, 8.25))
+}
+
+fn inverse(c: &mut Criterion) {
+    let matrix = view_matrix();
+    c.bench_function("general inverse", |b| b.iter(|| {
+        let m = black_box(matrix);
+        m.matrix_of_minors().matrix_of_cofactors().transpose() * (1.0 / m.determinant())
+    }));
+    c.bench_function("inverse", |b| b.iter(|| black_box(matrix).inverse()));
+    c.bench_function("inverse_affine", |b| b.iter(|| black_box(matrix).inverse_affine()));
+    c.bench_function("inverse_rigid", |b| b.iter(|| black_box(matrix).inverse_rigid()));
+}
+
+criterion_group!(benches, inverse);
+criterion_main!(benches);
@@ -0,0 +1,237 @@
+//! Descriptions of the axis conventions used by other tools, and conversions of data between them.
+//!
+//! ezcgmath itself is left-handed with Y as up, Z as forward and X as right. When importing assets authored elsewhere,
+//! create a `CoordinateConversion` from the source convention and apply it to every vector, rotation and transform:
+//! ```
+//! use ezcgmath::prelude::*;
+//! use ezcgmath::coordinate_system::CoordinateSystem;
+//!
+//! let conversion = CoordinateSystem::BLENDER.conversion_to(&CoordinateSystem::EZCGMATH);
+//! let up = conversion.vector3(&Vector3::new(0.0, 0.0, 1.0));
+//! assert_eq!(up, Vector3::new(0.0, 1.0, 0.0));
+//! ```
+
+use crate::{Error, Scalar};
+use crate::matrix::{Matrix3x3, Matrix4x4};
+use crate::point::Point3;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
+
+/// Whether the right axis is found with the left hand rule or the right hand rule.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// Looking along forward with up pointing upwards, right points to the right.
+    Left,
+    /// Looking along forward with up pointing upwards, right points to the left.
+    /// This is how right-handed tools see it when forward points towards the viewer.
+    Right,
+}
+
+/// One of the six signed principal axes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The direction of increasing X, (1, 0, 0).
+    PositiveX,
+    /// The direction of decreasing X, (-1, 0, 0).
+    NegativeX,
+    /// The direction of increasing Y, (0, 1, 0).
+    PositiveY,
+    /// The direction of decreasing Y, (0, -1, 0).
+    NegativeY,
+    /// The direction of increasing Z, (0, 0, 1).
+    PositiveZ,
+    /// The direction of decreasing Z, (0, 0, -1).
+    NegativeZ,
+}
+
+impl Axis {
+    /// All six axes.
+    pub const ALL: [Axis; 6] = [Axis::PositiveX, Axis::NegativeX, Axis::PositiveY, Axis::NegativeY, Axis::PositiveZ, Axis::NegativeZ];
+
+    /// Returns the unit vector pointing along this axis.
+    pub fn to_vector3(self) -> Vector3 {
+        match self {
+            Axis::PositiveX => Vector3::new(1.0, 0.0, 0.0),
+            Axis::NegativeX => Vector3::new(-1.0, 0.0, 0.0),
+            Axis::PositiveY => Vector3::new(0.0, 1.0, 0.0),
+            Axis::NegativeY => Vector3::new(0.0, -1.0, 0.0),
+            Axis::PositiveZ => Vector3::new(0.0, 0.0, 1.0),
+            Axis::NegativeZ => Vector3::new(0.0, 0.0, -1.0),
+        }
+    }
+
+    /// Returns the axis pointing the opposite way.
+    pub fn negate(self) -> Axis {
+        match self {
+            Axis::PositiveX => Axis::NegativeX,
+            Axis::NegativeX => Axis::PositiveX,
+            Axis::PositiveY => Axis::NegativeY,
+            Axis::NegativeY => Axis::PositiveY,
+            Axis::PositiveZ => Axis::NegativeZ,
+            Axis::NegativeZ => Axis::PositiveZ,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Axis::PositiveX | Axis::NegativeX => 0,
+            Axis::PositiveY | Axis::NegativeY => 1,
+            Axis::PositiveZ | Axis::NegativeZ => 2,
+        }
+    }
+}
+
+/// Describes a coordinate system convention by its handedness, and which axes point up and forward.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CoordinateSystem {
+    handedness: Handedness,
+    up: Axis,
+    forward: Axis,
+}
+
+impl CoordinateSystem {
+    /// The convention of this crate: left-handed, +Y up, +Z forward and +X right.
+    pub const EZCGMATH: CoordinateSystem = CoordinateSystem { handedness: Handedness::Left, up: Axis::PositiveY, forward: Axis::PositiveZ };
+
+    /// The convention of glTF: right-handed, +Y up, +Z forward and -X right.
+    pub const GLTF: CoordinateSystem = CoordinateSystem { handedness: Handedness::Right, up: Axis::PositiveY, forward: Axis::PositiveZ };
+
+    /// The convention of Blender: right-handed, +Z up, -Y forward and -X right.
+    /// This matches the axes that Blender's glTF exporter converts from.
+    pub const BLENDER: CoordinateSystem = CoordinateSystem { handedness: Handedness::Right, up: Axis::PositiveZ, forward: Axis::NegativeY };
+
+    /// Creates a new coordinate system description.
+    ///
+    /// This returns `Error::ParallelAxes` if up and forward lie along the same axis.
+    pub fn new(handedness: Handedness, up: Axis, forward: Axis) -> Result<Self, Error> {
+        if up.index() == forward.index() {
+            return Err(Error::ParallelAxes);
+        }
+        Ok(CoordinateSystem { handedness, up, forward })
+    }
+
+    /// The handedness of this coordinate system.
+    pub fn handedness(&self) -> Handedness {
+        self.handedness
+    }
+
+    /// The axis pointing up.
+    pub fn up(&self) -> Axis {
+        self.up
+    }
+
+    /// The axis pointing forward.
+    pub fn forward(&self) -> Axis {
+        self.forward
+    }
+
+    /// The axis pointing right, as determined by the handedness.
+    pub fn right(&self) -> Axis {
+        let up = self.up.to_vector3();
+        let forward = self.forward.to_vector3();
+        let right = match self.handedness {
+            Handedness::Left => up.cross(&forward),
+            Handedness::Right => forward.cross(&up),
+        };
+        Axis::ALL.iter().copied().find(|axis| axis.to_vector3() == right).unwrap()
+    }
+
+    /// Creates the conversion of data in this coordinate system into the `target` coordinate system.
+    pub fn conversion_to(&self, target: &CoordinateSystem) -> CoordinateConversion {
+        let source = self.basis();
+        let target = target.basis();
+        CoordinateConversion { matrix: source.transpose() * target }
+    }
+
+    /// The matrix whose rows are the right, up and forward axes.
+    fn basis(&self) -> Matrix3x3 {
+        let right = self.right().to_vector3();
+        let up = self.up.to_vector3();
+        let forward = self.forward.to_vector3();
+        Matrix3x3 {
+            c00: right.x,   c10: right.y,   c20: right.z,
+            c01: up.x,      c11: up.y,      c21: up.z,
+            c02: forward.x, c12: forward.y, c22: forward.z,
+        }
+    }
+}
+
+/// Converts data from one `CoordinateSystem` to another, so that right, up and forward keep their meaning.
+///
+/// Create one with `CoordinateSystem::conversion_to`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CoordinateConversion {
+    matrix: Matrix3x3,
+}
+
+impl CoordinateConversion {
+    /// The change of basis matrix. Multiplying a vector by it converts the vector.
+    pub fn matrix(&self) -> Matrix3x3 {
+        self.matrix
+    }
+
+    /// Returns true if the conversion changes handedness, in which case it is a reflection.
+    pub fn changes_handedness(&self) -> bool {
+        self.matrix.determinant() < 0.0
+    }
+
+    /// Returns the conversion in the opposite direction.
+    pub fn inverse(&self) -> CoordinateConversion {
+        CoordinateConversion { matrix: self.matrix.transpose() }
+    }
+
+    /// Converts a direction or offset.
+    pub fn vector3(&self, vector: &Vector3) -> Vector3 {
+        *vector * self.matrix
+    }
+
+    /// Converts a position.
+    pub fn point3(&self, point: &Point3) -> Point3 {
+        *point * self.matrix
+    }
+
+    /// Converts a rotation, so that it rotates converted vectors the same way the original rotated the originals.
+    pub fn quaternion(&self, rotation: &Quaternion) -> Quaternion {
+        // The axis of a rotation is a pseudovector, so a reflection flips it as well as moving it.
+        let sign: Scalar = if self.changes_handedness() { -1.0 } else { 1.0 };
+        let axis = Vector3::new(rotation.x, rotation.y, rotation.z) * self.matrix * sign;
+        Quaternion { x: axis.x, y: axis.y, z: axis.z, w: rotation.w }
+    }
+
+    /// Converts a transformation matrix, so that it transforms converted vectors the same way the original
+    /// transformed the originals.
+    pub fn matrix4x4(&self, matrix: &Matrix4x4) -> Matrix4x4 {
+        let conversion = self.matrix4x4_conversion();
+        conversion.transpose() * *matrix * conversion
+    }
+
+    /// Converts a scale, given along the local axes of an object.
+    ///
+    /// The converted scale is suitable for recomposing with a converted translation and rotation. Negative
+    /// components, which mirror the object, stay negative.
+    pub fn scale(&self, scale: &Vector3) -> Vector3 {
+        // Flipping an axis does not flip the scale along it, so only the permutation of the axes applies.
+        let m = self.matrix;
+        let permutation = Matrix3x3 {
+            c00: m.c00.abs(), c10: m.c10.abs(), c20: m.c20.abs(),
+            c01: m.c01.abs(), c11: m.c11.abs(), c21: m.c21.abs(),
+            c02: m.c02.abs(), c12: m.c12.abs(), c22: m.c22.abs(),
+        };
+        *scale * permutation
+    }
+
+    /// Converts transform data in the form of a translation, rotation and scale.
+    pub fn translation_rotation_scale(&self, translation: &Vector3, rotation: &Quaternion, scale: &Vector3) -> (Vector3, Quaternion, Vector3) {
+        (self.vector3(translation), self.quaternion(rotation), self.scale(scale))
+    }
+
+    fn matrix4x4_conversion(&self) -> Matrix4x4 {
+        let m = self.matrix;
+        Matrix4x4 {
+            c00: m.c00, c10: m.c10, c20: m.c20, c30: 0.0,
+            c01: m.c01, c11: m.c11, c21: m.c21, c31: 0.0,
+            c02: m.c02, c12: m.c12, c22: m.c22, c32: 0.0,
+            c03: 0.0,   c13: 0.0,   c23: 0.0,   c33: 1.0,
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{Error, Scalar};
+use crate::matrix::{Matrix3x3, Matrix4x4, DMatrix3x3, DMatrix4x4};
+use crate::quaternion::{Quaternion, DQuaternion};
+use crate::vector::{Vector3, DVector3};
+
+macro_rules! define_dual_quaternion {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $quaternion: ident, $matrix3x3: ident, $matrix4x4: ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub real: $quaternion,
+            pub dual: $quaternion,
+        }
+
+        impl Default for $type {
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl $type {
+            /// Creates the transformation that leaves everything where it is.
+            pub fn identity() -> Self {
+                Self { real: $quaternion::identity(), dual: $quaternion { x: 0.0, y: 0.0, z: 0.0, w: 0.0 } }
+            }
+
+            /// Creates a transformation that rotates, then translates. The rotation is expected to be normalized.
+            pub fn from_rotation_translation(rotation: &$quaternion, translation: &$vector3) -> Self {
+                // With vectors rotated by `v * q`, the dual part is `-0.5 * rotation * translation`.
+                let translation = $quaternion { x: translation.x * -0.5, y: translation.y * -0.5, z: translation.z * -0.5, w: 0.0 };
+                Self { real: *rotation, dual: *rotation * translation }
+            }
+
+            /// Creates a rotation. The rotation is expected to be normalized.
+            pub fn from_rotation(rotation: &$quaternion) -> Self {
+                Self::from_rotation_translation(rotation, &$vector3::default())
+            }
+
+            #[doc = concat!("Creates a translation from a `", stringify!($vector3), "`.")]
+            pub fn from_translation(translation: &$vector3) -> Self {
+                Self::from_rotation_translation(&$quaternion::identity(), translation)
+            }
+
+            #[doc = concat!("Creates a transformation from a `", stringify!($matrix4x4), "` that rotates and translates.")]
+            ///
+            /// A dual quaternion cannot represent scale or skew, so the rotation closest to the upper 3 x 3 of the
+            /// matrix is used, and the rest is discarded.
+            pub fn from_matrix(matrix: &$matrix4x4) -> Self {
+                let rotation = $quaternion::from_rotation_matrix(&$matrix3x3::from(*matrix).nearest_rotation());
+                Self::from_rotation_translation(&rotation, &$vector3::new(matrix.c03, matrix.c13, matrix.c23))
+            }
+
+            /// Returns the rotation part of the transformation.
+            pub fn rotation(&self) -> $quaternion {
+                self.real
+            }
+
+            /// Returns the translation part of the transformation, which is applied after the rotation.
+            pub fn translation(&self) -> $vector3 {
+                let translation = self.real.conjugate() * self.dual;
+                $vector3::new(translation.x * -2.0, translation.y * -2.0, translation.z * -2.0)
+            }
+
+            /// Calculates the inverse transformation, such that `self * self.inverse()` is the identity.
+            /// The dual quaternion is expected to be normalized.
+            pub fn inverse(&self) -> Self {
+                Self { real: self.real.conjugate(), dual: self.dual.conjugate() }
+            }
+
+            /// Scales the dual quaternion so that its rotation part has a length of one, and removes the part of the
+            /// dual that does not describe a translation, unless the length is less than or equal to `tolerance`.
+            ///
+            /// This returns `Error::ZeroLength` and leaves the dual quaternion unchanged rather than filling it with NaN.
+            pub fn try_normalize(&mut self, tolerance: $scalar) -> Result<(), Error> {
+                let length = self.real.dot(&self.real).sqrt();
+                if length.is_nan() || length <= tolerance {
+                    return Err(Error::ZeroLength);
+                }
+                let real = scale(&self.real, 1.0 / length);
+                let dual = scale(&self.dual, 1.0 / length);
+                self.dual = add(&dual, &scale(&real, -real.dot(&dual)));
+                self.real = real;
+                Ok(())
+            }
+
+            /// Returns a normalized copy of the dual quaternion, or `None` if its length is less than or equal to `tolerance`.
+            pub fn checked_normalize(&self, tolerance: $scalar) -> Option<Self> {
+                let mut result = *self;
+                result.try_normalize(tolerance).ok().map(|_| result)
+            }
+
+            /// Transforms a position, applying the rotation and then the translation.
+            pub fn transform_point3(&self, point: &$vector3) -> $vector3 {
+                *point * self.real + self.translation()
+            }
+
+            /// Transforms a direction, applying the rotation only.
+            pub fn transform_vector3(&self, vector: &$vector3) -> $vector3 {
+                *vector * self.real
+            }
+
+            /// Interpolates between this transformation and `other` along a screw motion, which is a rotation around
+            /// an axis combined with a translation along it, at a constant speed.
+            ///
+            /// This is screw linear interpolation (ScLERP), the dual quaternion counterpart of spherical linear
+            /// interpolation. It takes the shorter way around, and both dual quaternions are expected to be normalized.
+            pub fn sclerp(&self, other: &$type, t: $scalar) -> Self {
+                let mut other = *other;
+                if self.real.dot(&other.real) < 0.0 {
+                    other = Self { real: scale(&other.real, -1.0), dual: scale(&other.dual, -1.0) };
+                }
+                *self * (self.inverse() * other).powf(t)
+            }
+
+            /// Blends many transformations, given as `(transformation, weight)` pairs, by adding them up and normalizing
+            /// the sum. This is dual quaternion linear blending (DLB), as described by Kavan et al., "Geometric Skinning
+            /// with Approximate Dual Quaternion Blending" (2008).
+            ///
+            /// Each transformation is first flipped into the hemisphere of the first one, since `q` and `-q` describe the
+            /// same transformation. Unlike blending matrices, this keeps the volume around twisting joints, avoiding
+            /// the "candy wrapper" artifact. The weights are expected to be non-negative. If they add up to zero or
+            /// less, or the transformations cancel each other out so that their sum is nearly zero, the result is
+            /// `identity`.
+            pub fn weighted_blend(transformations: &[($type, $scalar)]) -> Self {
+                let total = transformations.iter().map(|(_, weight)| weight).sum::<$scalar>();
+                if total <= 0.0 {
+                    return Self::identity();
+                }
+                let first = transformations[0].0.real;
+                let zero = $quaternion { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+                let mut result = Self { real: zero, dual: zero };
+                for (transformation, weight) in transformations {
+                    let weight = if transformation.real.dot(&first) < 0.0 { -weight } else { *weight };
+                    result.real = add(&result.real, &scale(&transformation.real, weight));
+                    result.dual = add(&result.dual, &scale(&transformation.dual, weight));
+                }
+                if result.try_normalize(<$scalar>::EPSILON).is_err() {
+                    return Self::identity();
+                }
+                result
+            }
+
+            /// Raises a normalized dual quaternion to a power, scaling the angle and distance of its screw motion.
+            fn powf(&self, t: $scalar) -> Self {
+                let (real, dual) = if self.real.w < 0.0 {
+                    (scale(&self.real, -1.0), scale(&self.dual, -1.0))
+                } else {
+                    (self.real, self.dual)
+                };
+                let sin_half = $vector3::new(real.x, real.y, real.z).length();
+                if sin_half <= <$scalar>::EPSILON.sqrt() {
+                    // Without rotation, the screw motion is a translation, which scales linearly.
+                    let translation = $type { real, dual }.translation() * t;
+                    return Self::from_rotation_translation(&$quaternion::identity(), &translation);
+                }
+                let axis = $vector3::new(real.x, real.y, real.z) / sin_half;
+                let angle = 2.0 * sin_half.atan2(real.w);
+                let cos_half = real.w;
+                let pitch = -2.0 * dual.w / sin_half;
+                let moment = ($vector3::new(dual.x, dual.y, dual.z) - axis * (pitch * 0.5 * cos_half)) / sin_half;
+
+                let (sin_half, cos_half) = (angle * t * 0.5).sin_cos();
+                let pitch = pitch * t;
+                let real_vector = axis * sin_half;
+                let dual_vector = moment * sin_half + axis * (pitch * 0.5 * cos_half);
+                Self {
+                    real: $quaternion { x: real_vector.x, y: real_vector.y, z: real_vector.z, w: cos_half },
+                    dual: $quaternion { x: dual_vector.x, y: dual_vector.y, z: dual_vector.z, w: -pitch * 0.5 * sin_half },
+                }
+            }
+        }
+
+        impl std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    real: self.real * rhs.real,
+                    dual: add(&(self.real * rhs.dual), &(self.dual * rhs.real)),
+                }
+            }
+        }
+
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl From<$type> for $matrix4x4 {
+            fn from(transformation: $type) -> Self {
+                let translation = transformation.translation();
+                let mut result = $matrix4x4::from(transformation.real);
+                result.c03 = translation.x;
+                result.c13 = translation.y;
+                result.c23 = translation.z;
+                result
+            }
+        }
+
+        impl approx::AbsDiffEq for $type {
+            type Epsilon = $scalar;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$scalar>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.real.abs_diff_eq(&other.real, epsilon) && self.dual.abs_diff_eq(&other.dual, epsilon)
+            }
+        }
+
+        impl approx::RelativeEq for $type {
+            fn default_max_relative() -> Self::Epsilon {
+                <$scalar>::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                self.real.relative_eq(&other.real, epsilon, max_relative) && self.dual.relative_eq(&other.dual, epsilon, max_relative)
+            }
+        }
+
+        impl approx::UlpsEq for $type {
+            fn default_max_ulps() -> u32 {
+                <$scalar>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.real.ulps_eq(&other.real, epsilon, max_ulps) && self.dual.ulps_eq(&other.dual, epsilon, max_ulps)
+            }
+        }
+
+        fn add(a: &$quaternion, b: &$quaternion) -> $quaternion {
+            $quaternion { x: a.x + b.x, y: a.y + b.y, z: a.z + b.z, w: a.w + b.w }
+        }
+
+        fn scale(q: &$quaternion, factor: $scalar) -> $quaternion {
+            $quaternion { x: q.x * factor, y: q.y * factor, z: q.z * factor, w: q.w * factor }
+        }
+    };
+}
+
+mod single {
+    use super::*;
+    define_dual_quaternion!(
+        /// A rigid transformation, made of a rotation followed by a translation, stored as a pair of quaternions.
+        ///
+        /// Dual quaternions blend without the shrinking that blending matrices causes, which makes them the
+        /// usual choice for skinning. Like the other types, they are combined in reading order, so `a * b` applies `a` first.
+        DualQuaternion, Scalar, Vector3, Quaternion, Matrix3x3, Matrix4x4
+    );
+}
+pub use self::single::DualQuaternion;
+
+mod double {
+    use super::*;
+    define_dual_quaternion!(
+        /// A dual quaternion with double precision. See `DualQuaternion` for details.
+        DDualQuaternion, f64, DVector3, DQuaternion, DMatrix3x3, DMatrix4x4
+    );
+}
+pub use self::double::DDualQuaternion;
+
+impl DualQuaternion {
+    /// Converts to double precision. This conversion is lossless.
+    pub fn to_f64(&self) -> DDualQuaternion {
+        DDualQuaternion { real: self.real.to_f64(), dual: self.dual.to_f64() }
+    }
+}
+
+impl DDualQuaternion {
+    /// Converts to single precision, rounding every element to the nearest `f32`.
+    pub fn to_f32(&self) -> DualQuaternion {
+        DualQuaternion { real: self.real.to_f32(), dual: self.dual.to_f32() }
+    }
+}
+
+impl From<DualQuaternion> for DDualQuaternion {
+    fn from(transformation: DualQuaternion) -> Self {
+        transformation.to_f64()
+    }
+}
@@ -0,0 +1,22 @@
+/// The error type for the fallible operations in this crate, such as `try_inverse` and `try_normalize`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The determinant of the matrix is within the tolerance of zero, so it has no inverse.
+    SingularMatrix,
+    /// The length of the vector or quaternion is within the tolerance of zero, so it has no direction.
+    ZeroLength,
+    /// Two axes that must be perpendicular lie along the same line.
+    ParallelAxes,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::SingularMatrix => write!(f, "the matrix is singular and cannot be inverted"),
+            Error::ZeroLength => write!(f, "the length is zero and cannot be normalized"),
+            Error::ParallelAxes => write!(f, "the axes are parallel and cannot form a basis"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
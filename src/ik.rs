@@ -0,0 +1,283 @@
+use crate::{Degrees, Radians, Scalar};
+use crate::matrix::Matrix3x3;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
+
+/// Limits how far a bone may bend away from the direction of its parent bone.
+///
+/// The root bone of a chain has no parent bone, so its limit is measured from its direction before solving,
+/// and a hinge axis is in the local space of its rotation before solving.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum JointLimit {
+    /// The bone can point in any direction.
+    #[default]
+    Free,
+    /// The bone can bend in any direction, by up to `max_angle` away from the direction of its parent bone.
+    Cone { max_angle: Degrees },
+    /// The bone can only bend around `axis`, which is in the local space of the rotation of the parent joint,
+    /// by between `min` and `max` away from the direction of its parent bone. A positive angle bends the same
+    /// way as `Quaternion::from_axis_angle` rotates around the axis.
+    Hinge { axis: Vector3, min: Degrees, max: Degrees },
+}
+
+/// Controls when the iterative solvers stop.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolverSettings {
+    /// The largest number of passes over the chain.
+    pub max_iterations: usize,
+    /// The solver stops once the end of the chain is at most this far from the target.
+    pub tolerance: Scalar,
+}
+
+impl Default for SolverSettings {
+    fn default() -> Self {
+        Self { max_iterations: 20, tolerance: 1e-3 }
+    }
+}
+
+/// Bends a chain of two bones, such as a leg or an arm, so that its end reaches `target`, returning the distance
+/// that is left between the end and the target.
+///
+/// `positions` holds the root, middle and end joints in world space, and `rotations` their rotations in world space.
+/// The middle joint bends towards `pole`, such as a point in front of the knee. If the target is out of reach, the
+/// chain straightens and points at it. `limit` restricts the bend of the middle joint: the pole decides the plane
+/// of the bend, so only the angles of a `Hinge` apply, and bending backwards past straight is not possible.
+///
+/// The rotations of the root and middle joints turn with their bones, and the end joint turns with the lower bone.
+pub fn solve_two_bone(positions: &mut [Vector3; 3], rotations: &mut [Quaternion; 3], target: &Vector3, pole: &Vector3, limit: &JointLimit) -> Scalar {
+    let [root, middle, end] = *positions;
+    let upper_length = (middle - root).length();
+    let lower_length = (end - middle).length();
+    let mut forward = *target - root;
+    if forward.try_normalize(Scalar::EPSILON).is_err() {
+        forward = end - root;
+        forward.normalize();
+    }
+
+    // Keep the distance to the target within the reach of the chain and the range of the middle joint.
+    let (min_bend, max_bend) = match limit {
+        JointLimit::Free => (0.0, std::f32::consts::PI),
+        JointLimit::Cone { max_angle } => (0.0, radians(*max_angle)),
+        JointLimit::Hinge { min, max, .. } => (radians(*min).max(0.0), radians(*max)),
+    };
+    let reach = |bend: Scalar| {
+        (upper_length * upper_length + lower_length * lower_length + 2.0 * upper_length * lower_length * bend.cos()).sqrt()
+    };
+    let distance = (*target - root).length().min(reach(min_bend)).max(reach(max_bend)).max(Scalar::EPSILON);
+
+    let mut side = perpendicular(&(*pole - root), &forward)
+        .or_else(|| perpendicular(&(middle - root), &forward))
+        .unwrap_or_else(|| forward.any_orthogonal());
+    side.normalize();
+    let cos_upper = ((upper_length * upper_length + distance * distance - lower_length * lower_length)
+        / (2.0 * upper_length * distance)).clamp(-1.0, 1.0);
+    let sin_upper = (1.0 - cos_upper * cos_upper).sqrt();
+    let new_middle = root + (forward * cos_upper + side * sin_upper) * upper_length;
+    let new_end = root + forward * distance;
+
+    let old_normal = bend_normal(&(middle - root), &(end - middle), &(*pole - root));
+    let new_normal = bend_normal(&(new_middle - root), &(new_end - new_middle), &(*pole - root));
+    let upper_rotation = frame_rotation(&(middle - root), &old_normal, &(new_middle - root), &new_normal);
+    let lower_rotation = frame_rotation(&(end - middle), &old_normal, &(new_end - new_middle), &new_normal);
+    rotations[0] *= upper_rotation;
+    rotations[1] *= lower_rotation;
+    rotations[2] *= lower_rotation;
+    *positions = [root, new_middle, new_end];
+    (new_end - *target).length()
+}
+
+/// Bends a chain of joints so that its end reaches `target` with cyclic coordinate descent (CCD), returning the
+/// distance that is left between the end and the target.
+///
+/// `positions` holds the joints from the root to the end in world space, and `rotations` their rotations in world space.
+/// `limits` has one entry for each bone, which is one fewer than the number of joints, or is empty if the bones can
+/// bend freely. Each pass turns every joint, from the end towards the root, so that the end points at the target.
+/// CCD is simple and handles limits well, but tends to curl the joints near the end more than those near the root.
+///
+/// # Panics
+/// Panics if the chain has fewer than two joints, or if `rotations` or `limits` do not match the number of joints.
+pub fn solve_ccd(positions: &mut [Vector3], rotations: &mut [Quaternion], limits: &[JointLimit], target: &Vector3, settings: &SolverSettings) -> Scalar {
+    let constraints = Constraints::new(positions, rotations, limits);
+    let count = positions.len();
+    for _ in 0..settings.max_iterations {
+        if (positions[count - 1] - *target).length() <= settings.tolerance {
+            break;
+        }
+        for joint in (0..count - 1).rev() {
+            let origin = positions[joint];
+            let to_end = positions[count - 1] - origin;
+            let to_target = *target - origin;
+            if to_end.length() <= Scalar::EPSILON || to_target.length() <= Scalar::EPSILON {
+                continue;
+            }
+            let mut turn = Quaternion::from_rotation_arc(&to_end, &to_target);
+            let mut direction = positions[joint + 1] - origin;
+            direction.normalize();
+            let turned = direction * turn;
+            let constrained = constraints.apply(joint, &turned, positions, rotations);
+            if constrained != turned {
+                turn *= Quaternion::from_rotation_arc(&turned, &constrained);
+            }
+            for position in positions[joint + 1..].iter_mut() {
+                *position = origin + (*position - origin) * turn;
+            }
+            for rotation in rotations[joint..].iter_mut() {
+                *rotation *= turn;
+            }
+        }
+    }
+    (positions[count - 1] - *target).length()
+}
+
+/// Bends a chain of joints so that its end reaches `target` with forward and backward reaching inverse kinematics
+/// (FABRIK), returning the distance that is left between the end and the target.
+///
+/// The arguments are the same as for `solve_ccd`. Each pass drags the chain from the end to the target, then back
+/// to its root, keeping the length of every bone. FABRIK usually needs fewer passes than CCD and spreads the bend
+/// evenly along the chain, which suits spines, tails and tentacles.
+///
+/// # Panics
+/// Panics if the chain has fewer than two joints, or if `rotations` or `limits` do not match the number of joints.
+pub fn solve_fabrik(positions: &mut [Vector3], rotations: &mut [Quaternion], limits: &[JointLimit], target: &Vector3, settings: &SolverSettings) -> Scalar {
+    let constraints = Constraints::new(positions, rotations, limits);
+    let count = positions.len();
+    let root = positions[0];
+    let lengths: Vec<Scalar> = positions.windows(2).map(|bone| (bone[1] - bone[0]).length()).collect();
+    for _ in 0..settings.max_iterations {
+        if (positions[count - 1] - *target).length() <= settings.tolerance {
+            break;
+        }
+        let before: Vec<Vector3> = positions.windows(2).map(|bone| direction(&bone[0], &bone[1], &Vector3::unit_z())).collect();
+
+        positions[count - 1] = *target;
+        for joint in (0..count - 1).rev() {
+            let mut wanted = direction(&positions[joint], &positions[joint + 1], &before[joint]);
+            if joint + 2 < count {
+                let child_direction = direction(&positions[joint + 1], &positions[joint + 2], &before[joint + 1]);
+                wanted = constraints.apply_to_parent(joint + 1, &wanted, &child_direction, rotations);
+            }
+            positions[joint] = positions[joint + 1] - wanted * lengths[joint];
+        }
+
+        positions[0] = root;
+        for joint in 0..count - 1 {
+            let wanted = direction(&positions[joint], &positions[joint + 1], &before[joint]);
+            let constrained = constraints.apply(joint, &wanted, positions, rotations);
+            positions[joint + 1] = positions[joint] + constrained * lengths[joint];
+            let turn = Quaternion::from_rotation_arc(&before[joint], &constrained);
+            rotations[joint] *= turn;
+            if joint == count - 2 {
+                rotations[count - 1] *= turn;
+            }
+        }
+    }
+    (positions[count - 1] - *target).length()
+}
+
+/// The joint limits of a chain, with the frame of the root as it was before solving.
+struct Constraints<'a> {
+    limits: &'a [JointLimit],
+    root_direction: Vector3,
+    root_rotation: Quaternion,
+}
+
+impl<'a> Constraints<'a> {
+    fn new(positions: &[Vector3], rotations: &[Quaternion], limits: &'a [JointLimit]) -> Self {
+        assert!(positions.len() >= 2, "a chain needs at least two joints");
+        assert_eq!(positions.len(), rotations.len(), "every joint needs a rotation");
+        assert!(limits.is_empty() || limits.len() == positions.len() - 1, "limits must be empty, or one for each bone");
+        Self {
+            limits,
+            root_direction: direction(&positions[0], &positions[1], &Vector3::unit_z()),
+            root_rotation: rotations[0],
+        }
+    }
+
+    /// Restricts the direction of a bone, which is expected to be normalized, to the limit of its joint.
+    fn apply(&self, joint: usize, wanted: &Vector3, positions: &[Vector3], rotations: &[Quaternion]) -> Vector3 {
+        let limit = match self.limits.get(joint) {
+            Some(limit) => limit,
+            None => return *wanted,
+        };
+        if joint == 0 {
+            limit_direction(limit, wanted, &self.root_direction, &self.root_rotation, false)
+        } else {
+            let parent_direction = direction(&positions[joint - 1], &positions[joint], &self.root_direction);
+            limit_direction(limit, wanted, &parent_direction, &rotations[joint - 1], false)
+        }
+    }
+
+    /// Restricts the direction of the parent of a bone instead, keeping the bone itself in place.
+    fn apply_to_parent(&self, joint: usize, wanted: &Vector3, child_direction: &Vector3, rotations: &[Quaternion]) -> Vector3 {
+        match self.limits.get(joint) {
+            Some(limit) if joint > 0 => limit_direction(limit, wanted, child_direction, &rotations[joint - 1], true),
+            _ => *wanted,
+        }
+    }
+}
+
+/// Restricts a normalized direction to a limit measured from `reference`. When `reversed`, the direction is of the
+/// parent bone and the reference is its child, so the angles of a hinge are mirrored.
+fn limit_direction(limit: &JointLimit, wanted: &Vector3, reference: &Vector3, frame: &Quaternion, reversed: bool) -> Vector3 {
+    match limit {
+        JointLimit::Free => *wanted,
+        JointLimit::Cone { max_angle } => {
+            let max_angle = radians(*max_angle);
+            if wanted.dot(reference).clamp(-1.0, 1.0).acos() <= max_angle {
+                return *wanted;
+            }
+            let mut side = perpendicular(wanted, reference).unwrap_or_else(|| reference.any_orthogonal());
+            side.normalize();
+            *reference * max_angle.cos() + side * max_angle.sin()
+        }
+        JointLimit::Hinge { axis, min, max } => {
+            let mut axis = *axis * *frame;
+            axis.normalize();
+            let mut reference = match perpendicular(reference, &axis) {
+                Some(reference) => reference,
+                None => return *wanted,
+            };
+            reference.normalize();
+            let projected = perpendicular(wanted, &axis).unwrap_or(reference);
+            // Measure the angle the same way that `Quaternion::from_axis_angle` turns.
+            let angle = axis.dot(&projected.cross(&reference)).atan2(reference.dot(&projected));
+            let (min, max) = if reversed { (-radians(*max), -radians(*min)) } else { (radians(*min), radians(*max)) };
+            reference * Quaternion::from_axis_angle(&axis, Degrees::from(Radians(angle.clamp(min, max))))
+        }
+    }
+}
+
+/// The part of `vector` perpendicular to the normalized `axis`, or `None` if it is parallel to the axis.
+fn perpendicular(vector: &Vector3, axis: &Vector3) -> Option<Vector3> {
+    let mut result = *vector - *axis * vector.dot(axis);
+    result.try_normalize(Scalar::EPSILON.sqrt() * vector.length()).ok().map(|_| result)
+}
+
+/// The normalized direction from `from` to `to`, or `fallback` if they are in the same place.
+fn direction(from: &Vector3, to: &Vector3, fallback: &Vector3) -> Vector3 {
+    let mut result = *to - *from;
+    result.try_normalize(Scalar::EPSILON).map(|_| result).unwrap_or(*fallback)
+}
+
+/// The normal of the plane that a two bone chain bends in, using the pole when the chain is straight.
+fn bend_normal(upper: &Vector3, lower: &Vector3, pole: &Vector3) -> Vector3 {
+    let mut normal = upper.cross(lower);
+    if normal.try_normalize(Scalar::EPSILON.sqrt() * upper.length() * lower.length()).is_err() {
+        normal = pole.cross(upper);
+        if normal.try_normalize(Scalar::EPSILON.sqrt() * pole.length() * upper.length()).is_err() {
+            normal = upper.any_orthogonal();
+        }
+    }
+    normal
+}
+
+/// The rotation that turns a bone and the normal of its bend plane into their new directions.
+fn frame_rotation(old_direction: &Vector3, old_normal: &Vector3, new_direction: &Vector3, new_normal: &Vector3) -> Quaternion {
+    let old_frame = Matrix3x3::from_look_at(*old_direction, *old_normal);
+    let new_frame = Matrix3x3::from_look_at(*new_direction, *new_normal);
+    Quaternion::from_rotation_matrix(&(old_frame.transpose() * new_frame))
+}
+
+fn radians(angle: Degrees) -> Scalar {
+    Radians::from(angle).0
+}
@@ -0,0 +1,122 @@
+//! Conversions between ezcgmath types and the `cgmath` types.
+//!
+//! cgmath stores matrices column-major and transforms column vectors, so the rows of an ezcgmath matrix
+//! become the columns of the cgmath matrix, and a `Quaternion` is conjugated. See the `mint` conversions for details.
+
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::Scalar;
+
+impl From<cgmath::Vector2<Scalar>> for Vector2 {
+    fn from(vector: cgmath::Vector2<Scalar>) -> Self {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<Vector2> for cgmath::Vector2<Scalar> {
+    fn from(vector: Vector2) -> Self {
+        cgmath::Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<cgmath::Vector3<Scalar>> for Vector3 {
+    fn from(vector: cgmath::Vector3<Scalar>) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for cgmath::Vector3<Scalar> {
+    fn from(vector: Vector3) -> Self {
+        cgmath::Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<cgmath::Vector4<Scalar>> for Vector4 {
+    fn from(vector: cgmath::Vector4<Scalar>) -> Self {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<Vector4> for cgmath::Vector4<Scalar> {
+    fn from(vector: Vector4) -> Self {
+        cgmath::Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<cgmath::Quaternion<Scalar>> for Quaternion {
+    fn from(rotation: cgmath::Quaternion<Scalar>) -> Self {
+        Quaternion {
+            x: -rotation.v.x,
+            y: -rotation.v.y,
+            z: -rotation.v.z,
+            w: rotation.s,
+        }
+    }
+}
+
+impl From<Quaternion> for cgmath::Quaternion<Scalar> {
+    fn from(rotation: Quaternion) -> Self {
+        cgmath::Quaternion::new(rotation.w, -rotation.x, -rotation.y, -rotation.z)
+    }
+}
+
+impl From<cgmath::Matrix2<Scalar>> for Matrix2x2 {
+    fn from(m: cgmath::Matrix2<Scalar>) -> Self {
+        Matrix2x2 {
+            c00: m.x.x, c10: m.x.y,
+            c01: m.y.x, c11: m.y.y,
+        }
+    }
+}
+
+impl From<Matrix2x2> for cgmath::Matrix2<Scalar> {
+    fn from(m: Matrix2x2) -> Self {
+        cgmath::Matrix2::new(
+            m.c00, m.c10,
+            m.c01, m.c11,
+        )
+    }
+}
+
+impl From<cgmath::Matrix3<Scalar>> for Matrix3x3 {
+    fn from(m: cgmath::Matrix3<Scalar>) -> Self {
+        Matrix3x3 {
+            c00: m.x.x, c10: m.x.y, c20: m.x.z,
+            c01: m.y.x, c11: m.y.y, c21: m.y.z,
+            c02: m.z.x, c12: m.z.y, c22: m.z.z,
+        }
+    }
+}
+
+impl From<Matrix3x3> for cgmath::Matrix3<Scalar> {
+    fn from(m: Matrix3x3) -> Self {
+        cgmath::Matrix3::new(
+            m.c00, m.c10, m.c20,
+            m.c01, m.c11, m.c21,
+            m.c02, m.c12, m.c22,
+        )
+    }
+}
+
+impl From<cgmath::Matrix4<Scalar>> for Matrix4x4 {
+    fn from(m: cgmath::Matrix4<Scalar>) -> Self {
+        Matrix4x4 {
+            c00: m.x.x, c10: m.x.y, c20: m.x.z, c30: m.x.w,
+            c01: m.y.x, c11: m.y.y, c21: m.y.z, c31: m.y.w,
+            c02: m.z.x, c12: m.z.y, c22: m.z.z, c32: m.z.w,
+            c03: m.w.x, c13: m.w.y, c23: m.w.z, c33: m.w.w,
+        }
+    }
+}
+
+impl From<Matrix4x4> for cgmath::Matrix4<Scalar> {
+    fn from(m: Matrix4x4) -> Self {
+        cgmath::Matrix4::new(
+            m.c00, m.c10, m.c20, m.c30,
+            m.c01, m.c11, m.c21, m.c31,
+            m.c02, m.c12, m.c22, m.c32,
+            m.c03, m.c13, m.c23, m.c33,
+        )
+    }
+}
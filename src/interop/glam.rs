@@ -0,0 +1,129 @@
+//! Conversions between ezcgmath types and the `glam` types.
+//!
+//! glam stores matrices column-major and transforms column vectors, so the rows of an ezcgmath matrix
+//! become the columns of the glam matrix, and a `Quaternion` is conjugated. See the `mint` conversions for details.
+
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+
+impl From<glam::Vec2> for Vector2 {
+    fn from(vector: glam::Vec2) -> Self {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<Vector2> for glam::Vec2 {
+    fn from(vector: Vector2) -> Self {
+        glam::Vec2::new(vector.x, vector.y)
+    }
+}
+
+impl From<glam::Vec3> for Vector3 {
+    fn from(vector: glam::Vec3) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for glam::Vec3 {
+    fn from(vector: Vector3) -> Self {
+        glam::Vec3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<glam::Vec4> for Vector4 {
+    fn from(vector: glam::Vec4) -> Self {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<Vector4> for glam::Vec4 {
+    fn from(vector: Vector4) -> Self {
+        glam::Vec4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<glam::Quat> for Quaternion {
+    fn from(rotation: glam::Quat) -> Self {
+        Quaternion {
+            x: -rotation.x,
+            y: -rotation.y,
+            z: -rotation.z,
+            w: rotation.w,
+        }
+    }
+}
+
+impl From<Quaternion> for glam::Quat {
+    fn from(rotation: Quaternion) -> Self {
+        glam::Quat::from_xyzw(-rotation.x, -rotation.y, -rotation.z, rotation.w)
+    }
+}
+
+impl From<glam::Mat2> for Matrix2x2 {
+    fn from(m: glam::Mat2) -> Self {
+        let [c00, c10, c01, c11] = m.to_cols_array();
+        Matrix2x2 {
+            c00, c10,
+            c01, c11,
+        }
+    }
+}
+
+impl From<Matrix2x2> for glam::Mat2 {
+    fn from(m: Matrix2x2) -> Self {
+        glam::Mat2::from_cols_array(&[
+            m.c00, m.c10,
+            m.c01, m.c11,
+        ])
+    }
+}
+
+impl From<glam::Mat3> for Matrix3x3 {
+    fn from(m: glam::Mat3) -> Self {
+        let [c00, c10, c20, c01, c11, c21, c02, c12, c22] = m.to_cols_array();
+        Matrix3x3 {
+            c00, c10, c20,
+            c01, c11, c21,
+            c02, c12, c22,
+        }
+    }
+}
+
+impl From<Matrix3x3> for glam::Mat3 {
+    fn from(m: Matrix3x3) -> Self {
+        glam::Mat3::from_cols_array(&[
+            m.c00, m.c10, m.c20,
+            m.c01, m.c11, m.c21,
+            m.c02, m.c12, m.c22,
+        ])
+    }
+}
+
+impl From<glam::Mat4> for Matrix4x4 {
+    fn from(m: glam::Mat4) -> Self {
+        let [
+            c00, c10, c20, c30,
+            c01, c11, c21, c31,
+            c02, c12, c22, c32,
+            c03, c13, c23, c33,
+        ] = m.to_cols_array();
+        Matrix4x4 {
+            c00, c10, c20, c30,
+            c01, c11, c21, c31,
+            c02, c12, c22, c32,
+            c03, c13, c23, c33,
+        }
+    }
+}
+
+impl From<Matrix4x4> for glam::Mat4 {
+    fn from(m: Matrix4x4) -> Self {
+        glam::Mat4::from_cols_array(&[
+            m.c00, m.c10, m.c20, m.c30,
+            m.c01, m.c11, m.c21, m.c31,
+            m.c02, m.c12, m.c22, m.c32,
+            m.c03, m.c13, m.c23, m.c33,
+        ])
+    }
+}
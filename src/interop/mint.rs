@@ -0,0 +1,172 @@
+//! Conversions between ezcgmath types and the `mint` interoperability types.
+//!
+//! ezcgmath multiplies row vectors on the left of a matrix (`vector * matrix`), while the crates that
+//! speak mint multiply column vectors on the right (`matrix * vector`). The same transformation is therefore
+//! the transpose of itself between the two conventions, which these conversions take care of:
+//! - The rows of an ezcgmath matrix become the columns of a `mint::ColumnMatrix`, so a translation ends up in the `w` column.
+//! - A `mint::RowMatrix` is the transpose of that, so its rows are the columns of the ezcgmath matrix.
+//! - A `Quaternion` is conjugated, so that it rotates vectors the same way on either side.
+
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::point::{Point2, Point3};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::Scalar;
+
+macro_rules! impl_mint_vector {
+    ($type: ident, $($field:ident),+) => {
+        impl From<mint::$type<Scalar>> for $type {
+            fn from(vector: mint::$type<Scalar>) -> Self {
+                $type { $($field: vector.$field),+ }
+            }
+        }
+
+        impl From<$type> for mint::$type<Scalar> {
+            fn from(vector: $type) -> Self {
+                mint::$type { $($field: vector.$field),+ }
+            }
+        }
+
+        impl mint::IntoMint for $type {
+            type MintType = mint::$type<Scalar>;
+        }
+    };
+}
+
+impl_mint_vector!(Vector2, x, y);
+impl_mint_vector!(Vector3, x, y, z);
+impl_mint_vector!(Vector4, x, y, z, w);
+impl_mint_vector!(Point2, x, y);
+impl_mint_vector!(Point3, x, y, z);
+
+impl From<mint::Quaternion<Scalar>> for Quaternion {
+    fn from(rotation: mint::Quaternion<Scalar>) -> Self {
+        Quaternion {
+            x: -rotation.v.x,
+            y: -rotation.v.y,
+            z: -rotation.v.z,
+            w: rotation.s,
+        }
+    }
+}
+
+impl From<Quaternion> for mint::Quaternion<Scalar> {
+    fn from(rotation: Quaternion) -> Self {
+        mint::Quaternion {
+            v: mint::Vector3 { x: -rotation.x, y: -rotation.y, z: -rotation.z },
+            s: rotation.w,
+        }
+    }
+}
+
+impl mint::IntoMint for Quaternion {
+    type MintType = mint::Quaternion<Scalar>;
+}
+
+impl From<mint::ColumnMatrix2<Scalar>> for Matrix2x2 {
+    fn from(m: mint::ColumnMatrix2<Scalar>) -> Self {
+        Matrix2x2 {
+            c00: m.x.x, c10: m.x.y,
+            c01: m.y.x, c11: m.y.y,
+        }
+    }
+}
+
+impl From<Matrix2x2> for mint::ColumnMatrix2<Scalar> {
+    fn from(m: Matrix2x2) -> Self {
+        mint::ColumnMatrix2 {
+            x: mint::Vector2 { x: m.c00, y: m.c10 },
+            y: mint::Vector2 { x: m.c01, y: m.c11 },
+        }
+    }
+}
+
+impl From<mint::RowMatrix2<Scalar>> for Matrix2x2 {
+    fn from(m: mint::RowMatrix2<Scalar>) -> Self {
+        Matrix2x2::from(mint::ColumnMatrix2::from(m))
+    }
+}
+
+impl From<Matrix2x2> for mint::RowMatrix2<Scalar> {
+    fn from(m: Matrix2x2) -> Self {
+        mint::RowMatrix2::from(mint::ColumnMatrix2::from(m))
+    }
+}
+
+impl mint::IntoMint for Matrix2x2 {
+    type MintType = mint::ColumnMatrix2<Scalar>;
+}
+
+impl From<mint::ColumnMatrix3<Scalar>> for Matrix3x3 {
+    fn from(m: mint::ColumnMatrix3<Scalar>) -> Self {
+        Matrix3x3 {
+            c00: m.x.x, c10: m.x.y, c20: m.x.z,
+            c01: m.y.x, c11: m.y.y, c21: m.y.z,
+            c02: m.z.x, c12: m.z.y, c22: m.z.z,
+        }
+    }
+}
+
+impl From<Matrix3x3> for mint::ColumnMatrix3<Scalar> {
+    fn from(m: Matrix3x3) -> Self {
+        mint::ColumnMatrix3 {
+            x: mint::Vector3 { x: m.c00, y: m.c10, z: m.c20 },
+            y: mint::Vector3 { x: m.c01, y: m.c11, z: m.c21 },
+            z: mint::Vector3 { x: m.c02, y: m.c12, z: m.c22 },
+        }
+    }
+}
+
+impl From<mint::RowMatrix3<Scalar>> for Matrix3x3 {
+    fn from(m: mint::RowMatrix3<Scalar>) -> Self {
+        Matrix3x3::from(mint::ColumnMatrix3::from(m))
+    }
+}
+
+impl From<Matrix3x3> for mint::RowMatrix3<Scalar> {
+    fn from(m: Matrix3x3) -> Self {
+        mint::RowMatrix3::from(mint::ColumnMatrix3::from(m))
+    }
+}
+
+impl mint::IntoMint for Matrix3x3 {
+    type MintType = mint::ColumnMatrix3<Scalar>;
+}
+
+impl From<mint::ColumnMatrix4<Scalar>> for Matrix4x4 {
+    fn from(m: mint::ColumnMatrix4<Scalar>) -> Self {
+        Matrix4x4 {
+            c00: m.x.x, c10: m.x.y, c20: m.x.z, c30: m.x.w,
+            c01: m.y.x, c11: m.y.y, c21: m.y.z, c31: m.y.w,
+            c02: m.z.x, c12: m.z.y, c22: m.z.z, c32: m.z.w,
+            c03: m.w.x, c13: m.w.y, c23: m.w.z, c33: m.w.w,
+        }
+    }
+}
+
+impl From<Matrix4x4> for mint::ColumnMatrix4<Scalar> {
+    fn from(m: Matrix4x4) -> Self {
+        mint::ColumnMatrix4 {
+            x: mint::Vector4 { x: m.c00, y: m.c10, z: m.c20, w: m.c30 },
+            y: mint::Vector4 { x: m.c01, y: m.c11, z: m.c21, w: m.c31 },
+            z: mint::Vector4 { x: m.c02, y: m.c12, z: m.c22, w: m.c32 },
+            w: mint::Vector4 { x: m.c03, y: m.c13, z: m.c23, w: m.c33 },
+        }
+    }
+}
+
+impl From<mint::RowMatrix4<Scalar>> for Matrix4x4 {
+    fn from(m: mint::RowMatrix4<Scalar>) -> Self {
+        Matrix4x4::from(mint::ColumnMatrix4::from(m))
+    }
+}
+
+impl From<Matrix4x4> for mint::RowMatrix4<Scalar> {
+    fn from(m: Matrix4x4) -> Self {
+        mint::RowMatrix4::from(mint::ColumnMatrix4::from(m))
+    }
+}
+
+impl mint::IntoMint for Matrix4x4 {
+    type MintType = mint::ColumnMatrix4<Scalar>;
+}
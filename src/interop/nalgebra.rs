@@ -0,0 +1,134 @@
+//! Conversions between ezcgmath types and the `nalgebra` types.
+//!
+//! nalgebra transforms column vectors, so an ezcgmath matrix converts to its transpose, and a `Quaternion`
+//! is conjugated. See the `mint` conversions for details.
+
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::Scalar;
+
+impl From<nalgebra::Vector2<Scalar>> for Vector2 {
+    fn from(vector: nalgebra::Vector2<Scalar>) -> Self {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<Vector2> for nalgebra::Vector2<Scalar> {
+    fn from(vector: Vector2) -> Self {
+        nalgebra::Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<nalgebra::Vector3<Scalar>> for Vector3 {
+    fn from(vector: nalgebra::Vector3<Scalar>) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for nalgebra::Vector3<Scalar> {
+    fn from(vector: Vector3) -> Self {
+        nalgebra::Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<nalgebra::Vector4<Scalar>> for Vector4 {
+    fn from(vector: nalgebra::Vector4<Scalar>) -> Self {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<Vector4> for nalgebra::Vector4<Scalar> {
+    fn from(vector: Vector4) -> Self {
+        nalgebra::Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<nalgebra::Quaternion<Scalar>> for Quaternion {
+    fn from(rotation: nalgebra::Quaternion<Scalar>) -> Self {
+        Quaternion {
+            x: -rotation.i,
+            y: -rotation.j,
+            z: -rotation.k,
+            w: rotation.w,
+        }
+    }
+}
+
+impl From<Quaternion> for nalgebra::Quaternion<Scalar> {
+    fn from(rotation: Quaternion) -> Self {
+        nalgebra::Quaternion::new(rotation.w, -rotation.x, -rotation.y, -rotation.z)
+    }
+}
+
+impl From<nalgebra::UnitQuaternion<Scalar>> for Quaternion {
+    fn from(rotation: nalgebra::UnitQuaternion<Scalar>) -> Self {
+        Quaternion::from(rotation.into_inner())
+    }
+}
+
+impl From<Quaternion> for nalgebra::UnitQuaternion<Scalar> {
+    fn from(rotation: Quaternion) -> Self {
+        nalgebra::UnitQuaternion::new_normalize(rotation.into())
+    }
+}
+
+impl From<nalgebra::Matrix2<Scalar>> for Matrix2x2 {
+    fn from(m: nalgebra::Matrix2<Scalar>) -> Self {
+        Matrix2x2 {
+            c00: m.m11, c10: m.m21,
+            c01: m.m12, c11: m.m22,
+        }
+    }
+}
+
+impl From<Matrix2x2> for nalgebra::Matrix2<Scalar> {
+    fn from(m: Matrix2x2) -> Self {
+        nalgebra::Matrix2::new(
+            m.c00, m.c01,
+            m.c10, m.c11,
+        )
+    }
+}
+
+impl From<nalgebra::Matrix3<Scalar>> for Matrix3x3 {
+    fn from(m: nalgebra::Matrix3<Scalar>) -> Self {
+        Matrix3x3 {
+            c00: m.m11, c10: m.m21, c20: m.m31,
+            c01: m.m12, c11: m.m22, c21: m.m32,
+            c02: m.m13, c12: m.m23, c22: m.m33,
+        }
+    }
+}
+
+impl From<Matrix3x3> for nalgebra::Matrix3<Scalar> {
+    fn from(m: Matrix3x3) -> Self {
+        nalgebra::Matrix3::new(
+            m.c00, m.c01, m.c02,
+            m.c10, m.c11, m.c12,
+            m.c20, m.c21, m.c22,
+        )
+    }
+}
+
+impl From<nalgebra::Matrix4<Scalar>> for Matrix4x4 {
+    fn from(m: nalgebra::Matrix4<Scalar>) -> Self {
+        Matrix4x4 {
+            c00: m.m11, c10: m.m21, c20: m.m31, c30: m.m41,
+            c01: m.m12, c11: m.m22, c21: m.m32, c31: m.m42,
+            c02: m.m13, c12: m.m23, c22: m.m33, c32: m.m43,
+            c03: m.m14, c13: m.m24, c23: m.m34, c33: m.m44,
+        }
+    }
+}
+
+impl From<Matrix4x4> for nalgebra::Matrix4<Scalar> {
+    fn from(m: Matrix4x4) -> Self {
+        nalgebra::Matrix4::new(
+            m.c00, m.c01, m.c02, m.c03,
+            m.c10, m.c11, m.c12, m.c13,
+            m.c20, m.c21, m.c22, m.c23,
+            m.c30, m.c31, m.c32, m.c33,
+        )
+    }
+}
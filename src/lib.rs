@@ -9,27 +9,45 @@
 //! 
 //! ## Implementation Details
 //! The `Scalar` type is fixed to f32. This was chosen due to its straightforward compatibility with graphics APIs.
-//! It is up to the user to create their own abstractions if any limits are hit due to this.
+//! If you need more precision, for example for large world coordinates, every type has a double precision counterpart
+//! prefixed with `D` (`DVector3`, `DMatrix4x4`, `DQuaternion` and so on). Convert between the two explicitly with `to_f64` and `to_f32`.
 //! 
-//! The coordinate system is fixed to being left handed, with Y as up. If you wish to convert to a different 
-//! coordinate system you may achieve this by creating a reflection matrix and applying it at the appropriate point.
+//! The coordinate system is fixed to being left handed, with Y as up. If you wish to convert to or from a different 
+//! coordinate system, such as glTF's or Blender's, use a `CoordinateConversion` from the `coordinate_system` module.
 //! 
 //! Transformations in this API are applied in a row-major manor. As a reminder, this means that 
 //! transformations are applied in the order they are specified.
-//! For example, if you wanted to scale, _then_ rotate, and _then_ translate a position vector,
+//! For example, if you wanted to scale, _then_ rotate, and _then_ translate a position,
 //! you would write these transformations in "reading order":
 //! 
 //! ```
 //! use ezcgmath::prelude::*;
 //! use ezcgmath::approx::*;
 //!
-//! let position_vector = Vector3::new(5.0, 0.0, 0.0);
+//! let position = Point3::new(5.0, 0.0, 0.0);
 //! let scale_matrix = Matrix4x4::from_nonuniform_scale(&Vector3::new(2.0, 1.0, 1.0));
 //! let rotation_matrix = Quaternion::from_axis_angle(&Vector3::new(0.0, 1.0, 0.0), Degrees(90.0));
 //! let translation_matrix = Matrix4x4::from_translation(&Vector3::new(0.0, 0.0, -10.0));
-//! let transformed_vector = position_vector * scale_matrix * rotation_matrix * translation_matrix;
+//! let transformed_position = position * scale_matrix * rotation_matrix * translation_matrix;
 //! ```
 //!
+//! Multiplying a `Point3` by a `Matrix4x4` applies the translation, while multiplying a `Vector3` does not,
+//! as vectors are directions and offsets. Use `Matrix4x4::transform_normal` for surface normals.
+//!
+//! Earlier versions applied the translation to a `Vector3` as well. When upgrading, store positions as a `Point3`,
+//! or call `Matrix4x4::transform_point3` where a `Vector3` holds a position.
+//!
+//! To catch transformations applied in the wrong space, the `space` module offers tagged wrappers such as
+//! `Vector3In<World>` and `Matrix4x4Between<Local, World>`. They only compose when the spaces line up.
+//!
+//! ## Interoperability
+//! Conversions to and from other math crates are available behind optional cargo features:
+//! - `mint`: `From`/`Into` conversions with the `mint` types for vectors, quaternions and matrices.
+//! - `glam`, `nalgebra` and `cgmath`: the same conversions with the types of each of those crates.
+//!
+//! Those crates transform column vectors (`matrix * vector`), so matrices are transposed and quaternions are conjugated
+//! during conversion. The converted value always describes the same transformation as the original.
+//!
 //! ## Disclaimer
 //! ezcgmath is still very much a work in progress. If there are holes you'd like filling, 
 //! please feel free to open an issue on GitHub so we can start a conversation on it. If you'd like to 
@@ -75,15 +93,68 @@ impl From<Radians> for Degrees {
     }
 }
 
+mod interop;
+
+/// Converts an angle into radians at the precision of the type being built from it.
+pub(crate) trait AngleScalar {
+    fn radians(angle: Degrees) -> Self;
+    fn degrees(radians: Self) -> Degrees;
+}
+
+impl AngleScalar for f32 {
+    fn radians(angle: Degrees) -> Self {
+        Radians::from(angle).0
+    }
+
+    fn degrees(radians: Self) -> Degrees {
+        Degrees::from(Radians(radians))
+    }
+}
+
+impl AngleScalar for f64 {
+    fn radians(angle: Degrees) -> Self {
+        f64::from(angle.0).to_radians()
+    }
+
+    fn degrees(radians: Self) -> Degrees {
+        Degrees(radians.to_degrees() as Scalar)
+    }
+}
+
 /// approx crate re-export, useful for asserts on vector/matrix types.
 pub mod approx;
 
+/// Contains descriptions of coordinate system conventions, and conversions between them
+pub mod coordinate_system;
+
+/// Contains the DualQuaternion type, for rigid transformations
+pub mod dual_quaternion;
+
+/// Contains the Error type for fallible operations
+pub mod error;
+pub use self::error::Error;
+
+/// Contains inverse kinematics solvers for chains of joints
+pub mod ik;
+
 /// Contains Matrix types and operations
 pub mod matrix;
 
+/// Contains Point types and operations
+pub mod point;
+
 /// Contains the Quaternion type
 pub mod quaternion;
 
+/// Contains functions that deform vertices with a skeleton
+pub mod skinning;
+
+/// Contains wrappers that tag types with the coordinate space they belong to
+pub mod space;
+
+/// Contains the Transform2D type
+pub mod transform2d;
+
 /// Contains Vector types and operations
 pub mod vector;
 
@@ -91,6 +162,7 @@ pub mod vector;
 pub mod prelude {
     pub use crate::{Degrees, Radians};
     pub use crate::matrix::Matrix4x4;
+    pub use crate::point::Point3;
     pub use crate::quaternion::Quaternion;
     pub use crate::vector::Vector3;
 }
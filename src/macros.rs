@@ -55,49 +55,55 @@ macro_rules! impl_sub_self {
 }
 
 macro_rules! impl_mul_scalar {
-    ($type: ty, $($field:tt),+) => {
-        impl std::ops::Mul<crate::Scalar> for $type {
+    ($type: ty; $scalar: ty; $($field:tt),+) => {
+        impl std::ops::Mul<$scalar> for $type {
             type Output = Self;
 
-            fn mul(self, rhs: crate::Scalar) -> Self {
+            fn mul(self, rhs: $scalar) -> Self {
                 let mut result = Self::default();
                 $(result.$field = self.$field * rhs;)+
                 result
             }
         }
 
-        impl std::ops::MulAssign<crate::Scalar> for $type {
-            fn mul_assign(&mut self, rhs: crate::Scalar) {
+        impl std::ops::MulAssign<$scalar> for $type {
+            fn mul_assign(&mut self, rhs: $scalar) {
                 $(self.$field *= rhs;)+
             }
         }
-    }
+    };
+    ($type: ty, $($field:tt),+) => {
+        impl_mul_scalar!($type; crate::Scalar; $($field),+);
+    };
 }
 
 macro_rules! impl_div_scalar {
-    ($type: ty, $($field:tt),+) => {
-        impl std::ops::Div<crate::Scalar> for $type {
+    ($type: ty; $scalar: ty; $($field:tt),+) => {
+        impl std::ops::Div<$scalar> for $type {
             type Output = Self;
 
-            fn div(self, rhs: crate::Scalar) -> Self::Output {
+            fn div(self, rhs: $scalar) -> Self::Output {
                 let mut result = Self::default();
                 $(result.$field = self.$field / rhs;)+
                 result
             }
         }
 
-        impl std::ops::DivAssign<crate::Scalar> for $type {
-            fn div_assign(&mut self, rhs: crate::Scalar) {
+        impl std::ops::DivAssign<$scalar> for $type {
+            fn div_assign(&mut self, rhs: $scalar) {
                 $(self.$field /= rhs;)+
             }
         }
-    }
+    };
+    ($type: ty, $($field:tt),+) => {
+        impl_div_scalar!($type; crate::Scalar; $($field),+);
+    };
 }
 
 macro_rules! impl_approx {
-    ($type: ty, $($field:tt),+) => {
+    ($type: ty; $scalar: ty; $($field:tt),+) => {
         impl approx::AbsDiffEq for $type {
-            type Epsilon = crate::Scalar;
+            type Epsilon = $scalar;
 
             fn default_epsilon() -> Self::Epsilon {
                 Self::Epsilon::default_epsilon()
@@ -134,4 +140,31 @@ macro_rules! impl_approx {
             }
         }
     };
+    ($type: ty, $($field:tt),+) => {
+        impl_approx!($type; crate::Scalar; $($field),+);
+    };
+}
+
+macro_rules! impl_precision_conversion {
+    ($single: ident, $double: ident, $($field:tt),+) => {
+        impl $single {
+            /// Converts to double precision. This conversion is lossless.
+            pub fn to_f64(&self) -> $double {
+                $double { $($field: self.$field as f64),+ }
+            }
+        }
+
+        impl $double {
+            /// Converts to single precision, rounding every element to the nearest `f32`.
+            pub fn to_f32(&self) -> $single {
+                $single { $($field: self.$field as f32),+ }
+            }
+        }
+
+        impl From<$single> for $double {
+            fn from(value: $single) -> Self {
+                value.to_f64()
+            }
+        }
+    };
 }
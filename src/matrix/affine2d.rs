@@ -0,0 +1,201 @@
+use super::{Matrix2x2, Matrix3x3, DMatrix2x2, DMatrix3x3};
+use crate::{AngleScalar, Degrees, Error, Scalar};
+use crate::vector::*;
+
+macro_rules! define_affine2d {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector2: ident, $matrix2x2: ident, $matrix3x3: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub c00: $scalar, pub c10: $scalar,
+            pub c01: $scalar, pub c11: $scalar,
+            pub c02: $scalar, pub c12: $scalar,
+        }
+        impl_approx!($type; $scalar; c00, c10, c01, c11, c02, c12);
+
+        impl $type {
+            /// Creates an instance of the identity transformation.
+            pub const fn identity() -> Self {
+                Self {
+                    c00: 1.0, c10: 0.0,
+                    c01: 0.0, c11: 1.0,
+                    c02: 0.0, c12: 0.0,
+                }
+            }
+
+            #[doc = concat!("Creates a transformation from a `", stringify!($matrix2x2), "`, applied first, and a translation, applied after it.")]
+            pub const fn from_linear_translation(linear: &$matrix2x2, translation: &$vector2) -> Self {
+                Self {
+                    c00: linear.c00,    c10: linear.c10,
+                    c01: linear.c01,    c11: linear.c11,
+                    c02: translation.x, c12: translation.y,
+                }
+            }
+
+            #[doc = concat!("Creates a translation from a `", stringify!($vector2), "`.")]
+            pub const fn from_translation(translation: &$vector2) -> Self {
+                Self {
+                    c00: 1.0,           c10: 0.0,
+                    c01: 0.0,           c11: 1.0,
+                    c02: translation.x, c12: translation.y,
+                }
+            }
+
+            /// Creates a rotation by the given angle. For an angle in `Radians`, convert it with `Degrees::from`.
+            ///
+            /// This matches `Matrix3x3::from_rotation_z`, so with Y as up a positive angle rotates clockwise.
+            pub fn from_rotation(angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                Self {
+                    c00: c,   c10: -s,
+                    c01: s,   c11: c,
+                    c02: 0.0, c12: 0.0,
+                }
+            }
+
+            #[doc = concat!("Creates a non-uniform scale from a `", stringify!($vector2), "`.")]
+            pub const fn from_nonuniform_scale(scale: &$vector2) -> Self {
+                Self {
+                    c00: scale.x, c10: 0.0,
+                    c01: 0.0,     c11: scale.y,
+                    c02: 0.0,     c12: 0.0,
+                }
+            }
+
+            /// Creates a uniform scale from a scalar.
+            pub const fn from_scale(scale: $scalar) -> Self {
+                Self {
+                    c00: scale, c10: 0.0,
+                    c01: 0.0,   c11: scale,
+                    c02: 0.0,   c12: 0.0,
+                }
+            }
+
+            /// Creates a skew, in the same way as the CSS `skew()` function. `angle_x` tilts the Y axis towards X,
+            /// so that points move along X in proportion to their Y, and `angle_y` does the same for Y in proportion to X.
+            pub fn from_skew(angle_x: Degrees, angle_y: Degrees) -> Self {
+                let tan_x = <$scalar as AngleScalar>::radians(angle_x).tan();
+                let tan_y = <$scalar as AngleScalar>::radians(angle_y).tan();
+                Self {
+                    c00: 1.0,   c10: tan_y,
+                    c01: tan_x, c11: 1.0,
+                    c02: 0.0,   c12: 0.0,
+                }
+            }
+
+            #[doc = concat!("Returns the linear part of the transformation, as a `", stringify!($matrix2x2), "`.")]
+            pub const fn linear(&self) -> $matrix2x2 {
+                $matrix2x2 {
+                    c00: self.c00, c10: self.c10,
+                    c01: self.c01, c11: self.c11,
+                }
+            }
+
+            /// Returns the translation part of the transformation.
+            pub const fn translation(&self) -> $vector2 {
+                $vector2::new(self.c02, self.c12)
+            }
+
+            /// Calculates the determinant of the linear part of the transformation.
+            pub fn determinant(&self) -> $scalar {
+                self.c00 * self.c11 - self.c10 * self.c01
+            }
+
+            /// Calculates the Inverse transformation, such that `self * self.inverse() == Self::identity()`.
+            ///
+            /// If the determinant is zero, the result is filled with NaN or infinity.
+            /// Use `try_inverse` or `checked_inverse` when that can happen.
+            pub fn inverse(&self) -> $type {
+                let inverse_determinant = 1.0 / self.determinant();
+                let c00 = self.c11 * inverse_determinant;
+                let c10 = -self.c10 * inverse_determinant;
+                let c01 = -self.c01 * inverse_determinant;
+                let c11 = self.c00 * inverse_determinant;
+                Self {
+                    c00, c10,
+                    c01, c11,
+                    c02: -(self.c02 * c00 + self.c12 * c01),
+                    c12: -(self.c02 * c10 + self.c12 * c11),
+                }
+            }
+
+            /// Calculates the Inverse transformation, unless the absolute value of the determinant is less than or equal to `tolerance`.
+            pub fn try_inverse(&self, tolerance: $scalar) -> Result<$type, Error> {
+                let determinant = self.determinant();
+                if determinant.is_nan() || determinant.abs() <= tolerance {
+                    return Err(Error::SingularMatrix);
+                }
+                Ok(self.inverse())
+            }
+
+            /// Calculates the Inverse transformation, or `None` if the absolute value of the determinant is less than or equal to `tolerance`.
+            pub fn checked_inverse(&self, tolerance: $scalar) -> Option<$type> {
+                self.try_inverse(tolerance).ok()
+            }
+
+            /// Transforms a position, applying the translation.
+            pub fn transform_point2(&self, point: &$vector2) -> $vector2 {
+                $vector2::new(
+                    point.x * self.c00 + point.y * self.c01 + self.c02,
+                    point.x * self.c10 + point.y * self.c11 + self.c12,
+                )
+            }
+
+            /// Transforms a direction, ignoring the translation.
+            pub fn transform_vector2(&self, vector: &$vector2) -> $vector2 {
+                $vector2::new(
+                    vector.x * self.c00 + vector.y * self.c01,
+                    vector.x * self.c10 + vector.y * self.c11,
+                )
+            }
+        }
+
+        impl std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    c00: self.c00 * rhs.c00 + self.c10 * rhs.c01,
+                    c10: self.c00 * rhs.c10 + self.c10 * rhs.c11,
+
+                    c01: self.c01 * rhs.c00 + self.c11 * rhs.c01,
+                    c11: self.c01 * rhs.c10 + self.c11 * rhs.c11,
+
+                    c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + rhs.c02,
+                    c12: self.c02 * rhs.c10 + self.c12 * rhs.c11 + rhs.c12,
+                }
+            }
+        }
+
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl From<$type> for $matrix3x3 {
+            fn from(m: $type) -> Self {
+                $matrix3x3 {
+                    c00: m.c00, c10: m.c10, c20: 0.0,
+                    c01: m.c01, c11: m.c11, c21: 0.0,
+                    c02: m.c02, c12: m.c12, c22: 1.0,
+                }
+            }
+        }
+    };
+}
+
+define_affine2d!(
+    /// A 2D affine transformation, made of a 2 x 2 linear part and a translation.
+    ///
+    /// This is a 3 x 3 homogeneous matrix with its constant last column left out. Like the other matrices,
+    /// transformations are applied in reading order, so `scale * rotation * translation` scales first.
+    Affine2D, Scalar, Vector2, Matrix2x2, Matrix3x3
+);
+define_affine2d!(
+    /// A 2D affine transformation, with double precision. See `Affine2D` for details.
+    DAffine2D, f64, DVector2, DMatrix2x2, DMatrix3x3
+);
+
+impl_precision_conversion!(Affine2D, DAffine2D, c00, c10, c01, c11, c02, c12);
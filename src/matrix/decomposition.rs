@@ -0,0 +1,296 @@
+use super::{Matrix, Matrix3x3, Matrix4x4, DMatrix, DMatrix3x3, DMatrix4x4};
+use crate::Error;
+use crate::vector::{Vector3, Vector4, DVector3, DVector4};
+
+macro_rules! define_decompositions {
+    ($matrix: ident, $lu: ident, $qr: ident, $scalar: ty) => {
+        #[doc = concat!("The LU decomposition of a square `", stringify!($matrix), "`, with partial pivoting.")]
+        ///
+        /// The rows of the matrix are reordered by `permutation`, and the reordered matrix equals `l() * u()`.
+        /// Create one with `lu`, then use it to solve as many right hand sides as you need.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $lu<const N: usize> {
+            lu: $matrix<N, N>,
+            permutation: [usize; N],
+            sign: $scalar,
+            scale: $scalar,
+        }
+
+        impl<const N: usize> $lu<N> {
+            /// The unit lower triangular factor.
+            pub fn l(&self) -> $matrix<N, N> {
+                let mut result = $matrix::<N, N>::identity();
+                for i in 0..N {
+                    for j in 0..i {
+                        result.rows[i][j] = self.lu.rows[i][j];
+                    }
+                }
+                result
+            }
+
+            /// The upper triangular factor.
+            pub fn u(&self) -> $matrix<N, N> {
+                let mut result = $matrix::<N, N>::zero();
+                for i in 0..N {
+                    for j in i..N {
+                        result.rows[i][j] = self.lu.rows[i][j];
+                    }
+                }
+                result
+            }
+
+            /// Row `i` of the reordered matrix is row `permutation()[i]` of the original.
+            pub fn permutation(&self) -> [usize; N] {
+                self.permutation
+            }
+
+            /// Calculates the determinant of the original matrix.
+            pub fn determinant(&self) -> $scalar {
+                (0..N).fold(self.sign, |result, i| result * self.lu.rows[i][i])
+            }
+
+            /// Returns true if a pivot is zero, relative to the largest element of the original matrix and the precision of the scalar type.
+            pub fn is_singular(&self) -> bool {
+                let threshold = self.scale * <$scalar>::EPSILON * N as $scalar;
+                self.scale == 0.0 || (0..N).any(|i| self.lu.rows[i][i].is_nan() || self.lu.rows[i][i].abs() <= threshold)
+            }
+
+            /// Finds `x` such that `matrix * x == b`, where `x` and `b` are columns.
+            ///
+            /// This returns `Error::SingularMatrix` if the matrix `is_singular`.
+            pub fn solve(&self, b: &[$scalar; N]) -> Result<[$scalar; N], Error> {
+                if self.is_singular() {
+                    return Err(Error::SingularMatrix);
+                }
+                let rows = &self.lu.rows;
+                let mut x = [0.0; N];
+                for i in 0..N {
+                    x[i] = b[self.permutation[i]] - (0..i).map(|j| rows[i][j] * x[j]).sum::<$scalar>();
+                }
+                for i in (0..N).rev() {
+                    x[i] = (x[i] - (i + 1..N).map(|j| rows[i][j] * x[j]).sum::<$scalar>()) / rows[i][i];
+                }
+                Ok(x)
+            }
+        }
+
+        #[doc = concat!("The QR decomposition of a square `", stringify!($matrix), "`, computed with Householder reflections.")]
+        ///
+        /// The matrix equals `q() * r()`, where `q()` is orthogonal and `r()` is upper triangular.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $qr<const N: usize> {
+            q: $matrix<N, N>,
+            r: $matrix<N, N>,
+            scale: $scalar,
+        }
+
+        impl<const N: usize> $qr<N> {
+            /// The orthogonal factor.
+            pub fn q(&self) -> $matrix<N, N> {
+                self.q
+            }
+
+            /// The upper triangular factor.
+            pub fn r(&self) -> $matrix<N, N> {
+                self.r
+            }
+
+            /// Finds `x` such that `matrix * x == b`, where `x` and `b` are columns.
+            ///
+            /// This returns `Error::SingularMatrix` if a diagonal element of `r()` is zero, relative to the largest
+            /// element of the original matrix and the precision of the scalar type.
+            pub fn solve(&self, b: &[$scalar; N]) -> Result<[$scalar; N], Error> {
+                let threshold = self.scale * <$scalar>::EPSILON * N as $scalar;
+                let rows = &self.r.rows;
+                if self.scale == 0.0 || (0..N).any(|i| rows[i][i].is_nan() || rows[i][i].abs() <= threshold) {
+                    return Err(Error::SingularMatrix);
+                }
+                let mut x = [0.0; N];
+                for i in 0..N {
+                    x[i] = (0..N).map(|j| self.q.rows[j][i] * b[j]).sum();
+                }
+                for i in (0..N).rev() {
+                    x[i] = (x[i] - (i + 1..N).map(|j| rows[i][j] * x[j]).sum::<$scalar>()) / rows[i][i];
+                }
+                Ok(x)
+            }
+        }
+
+        impl<const N: usize> $matrix<N, N> {
+            /// Decomposes this matrix into lower and upper triangular factors, with partial pivoting.
+            pub fn lu(&self) -> $lu<N> {
+                let mut lu = *self;
+                let mut permutation = [0; N];
+                for (i, p) in permutation.iter_mut().enumerate() {
+                    *p = i;
+                }
+                let mut sign = 1.0;
+                for k in 0..N {
+                    let pivot = (k..N).fold(k, |best, i| if lu.rows[i][k].abs() > lu.rows[best][k].abs() { i } else { best });
+                    if pivot != k {
+                        lu.rows.swap(pivot, k);
+                        permutation.swap(pivot, k);
+                        sign = -sign;
+                    }
+                    let diagonal = lu.rows[k][k];
+                    if diagonal == 0.0 {
+                        continue;
+                    }
+                    for i in k + 1..N {
+                        let factor = lu.rows[i][k] / diagonal;
+                        lu.rows[i][k] = factor;
+                        for j in k + 1..N {
+                            lu.rows[i][j] -= factor * lu.rows[k][j];
+                        }
+                    }
+                }
+                $lu { lu, permutation, sign, scale: self.max_abs() }
+            }
+
+            /// Decomposes this matrix into orthogonal and upper triangular factors, using Householder reflections.
+            pub fn qr(&self) -> $qr<N> {
+                let mut q = $matrix::<N, N>::identity();
+                let mut r = *self;
+                for k in 0..N.saturating_sub(1) {
+                    let norm = (k..N).map(|i| r.rows[i][k] * r.rows[i][k]).sum::<$scalar>().sqrt();
+                    if norm == 0.0 {
+                        continue;
+                    }
+                    // Reflect the column onto the diagonal, choosing the sign that avoids cancellation.
+                    let alpha = if r.rows[k][k] > 0.0 { -norm } else { norm };
+                    let mut v = [0.0; N];
+                    for i in k..N {
+                        v[i] = r.rows[i][k];
+                    }
+                    v[k] -= alpha;
+                    let v_length_squared = (k..N).map(|i| v[i] * v[i]).sum::<$scalar>();
+                    if v_length_squared == 0.0 {
+                        continue;
+                    }
+                    for j in 0..N {
+                        let factor = 2.0 * (k..N).map(|i| v[i] * r.rows[i][j]).sum::<$scalar>() / v_length_squared;
+                        for i in k..N {
+                            r.rows[i][j] -= factor * v[i];
+                        }
+                    }
+                    for row in q.rows.iter_mut() {
+                        let factor = 2.0 * (k..N).map(|i| row[i] * v[i]).sum::<$scalar>() / v_length_squared;
+                        for i in k..N {
+                            row[i] -= factor * v[i];
+                        }
+                    }
+                    for i in k + 1..N {
+                        r.rows[i][k] = 0.0;
+                    }
+                }
+                $qr { q, r, scale: self.max_abs() }
+            }
+
+            /// Finds `x` such that `self * x == b`, where `x` and `b` are columns, using the LU decomposition.
+            pub fn solve(&self, b: &[$scalar; N]) -> Result<[$scalar; N], Error> {
+                self.lu().solve(b)
+            }
+
+            /// Estimates the condition number of this matrix in the 1-norm, which is how much errors in `b` can be
+            /// magnified in the solution of `self * x == b`. Returns infinity for a singular matrix.
+            ///
+            /// A result near `1.0` is well conditioned. As a rule of thumb, a solution loses one decimal digit of
+            /// precision for every power of ten in the condition number.
+            pub fn condition_number(&self) -> $scalar {
+                let lu = self.lu();
+                let mut inverse = $matrix::<N, N>::zero();
+                for j in 0..N {
+                    let mut e = [0.0; N];
+                    e[j] = 1.0;
+                    match lu.solve(&e) {
+                        Ok(column) => for i in 0..N {
+                            inverse.rows[i][j] = column[i];
+                        },
+                        Err(_) => return <$scalar>::INFINITY,
+                    }
+                }
+                self.norm1() * inverse.norm1()
+            }
+
+            /// Calculates the number of linearly independent rows, using Gaussian elimination with full pivoting.
+            ///
+            /// A pivot counts as zero when it is less than or equal to `tolerance` times the largest element of the matrix.
+            pub fn rank(&self, tolerance: $scalar) -> usize {
+                let threshold = tolerance * self.max_abs();
+                let mut m = *self;
+                let mut rank = 0;
+                while rank < N {
+                    let mut pivot = (rank, rank);
+                    for i in rank..N {
+                        for j in rank..N {
+                            if m.rows[i][j].abs() > m.rows[pivot.0][pivot.1].abs() {
+                                pivot = (i, j);
+                            }
+                        }
+                    }
+                    let largest = m.rows[pivot.0][pivot.1];
+                    if largest.is_nan() || largest.abs() <= threshold {
+                        break;
+                    }
+                    m.rows.swap(rank, pivot.0);
+                    for row in m.rows.iter_mut() {
+                        row.swap(rank, pivot.1);
+                    }
+                    for i in rank + 1..N {
+                        let factor = m.rows[i][rank] / m.rows[rank][rank];
+                        for j in rank..N {
+                            m.rows[i][j] -= factor * m.rows[rank][j];
+                        }
+                    }
+                    rank += 1;
+                }
+                rank
+            }
+
+            fn max_abs(&self) -> $scalar {
+                self.rows.iter().flat_map(|row| row.iter()).fold(0.0, |max, element| max.max(element.abs()))
+            }
+
+            fn norm1(&self) -> $scalar {
+                (0..N).map(|j| self.rows.iter().map(|row| row[j].abs()).sum::<$scalar>()).fold(0.0, <$scalar>::max)
+            }
+        }
+    };
+}
+
+/// Adds solvers to a named square matrix type, following its convention of multiplying row vectors on the left.
+macro_rules! impl_named_solvers {
+    ($named: ident, $generic: ident, $n: literal, $scalar: ty, $vector: ident, $($field: ident),+) => {
+        impl $named {
+            #[doc = concat!("Finds the `", stringify!($vector), "` `x` such that `x * self == b`, which is `b * self.inverse()`")]
+            /// without explicitly inverting the matrix. This is more accurate and faster than inverting for a single solve.
+            ///
+            /// This uses an LU decomposition with partial pivoting, and returns `Error::SingularMatrix` if a pivot is
+            /// zero relative to the largest element of the matrix and the precision of the scalar type.
+            /// An ill-conditioned matrix may still give an inaccurate result, see `condition_number`.
+            pub fn solve(&self, b: &$vector) -> Result<$vector, Error> {
+                let [$($field),+] = $generic::<$n, $n>::from(*self).transpose().solve(&[$(b.$field),+])?;
+                Ok($vector { $($field),+ })
+            }
+
+            #[doc = concat!("Estimates the condition number of this matrix. See `", stringify!($generic), "::condition_number`.")]
+            pub fn condition_number(&self) -> $scalar {
+                $generic::<$n, $n>::from(*self).condition_number()
+            }
+
+            /// Calculates the number of linearly independent rows, which is the same as the number of linearly independent columns.
+            #[doc = concat!("See `", stringify!($generic), "::rank`.")]
+            pub fn rank(&self, tolerance: $scalar) -> usize {
+                $generic::<$n, $n>::from(*self).rank(tolerance)
+            }
+        }
+    };
+}
+
+define_decompositions!(Matrix, Lu, Qr, f32);
+define_decompositions!(DMatrix, DLu, DQr, f64);
+
+impl_named_solvers!(Matrix3x3, Matrix, 3, f32, Vector3, x, y, z);
+impl_named_solvers!(Matrix4x4, Matrix, 4, f32, Vector4, x, y, z, w);
+impl_named_solvers!(DMatrix3x3, DMatrix, 3, f64, DVector3, x, y, z);
+impl_named_solvers!(DMatrix4x4, DMatrix, 4, f64, DVector4, x, y, z, w);
@@ -0,0 +1,355 @@
+use super::{Matrix1x3, Matrix2x2, Matrix3x3, Matrix4x4, DMatrix1x3, DMatrix2x2, DMatrix3x3, DMatrix4x4};
+use crate::Scalar;
+
+macro_rules! define_generic_matrix {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $type<const R: usize, const C: usize> {
+            pub rows: [[$scalar; C]; R],
+        }
+
+        impl<const R: usize, const C: usize> Default for $type<R, C> {
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl<const R: usize, const C: usize> $type<R, C> {
+            /// Creates a matrix with every element set to zero.
+            pub const fn zero() -> Self {
+                Self { rows: [[0.0; C]; R] }
+            }
+
+            /// Creates a matrix with ones on the main diagonal and zeros everywhere else.
+            pub fn identity() -> Self {
+                let mut result = Self::zero();
+                for i in 0..R.min(C) {
+                    result.rows[i][i] = 1.0;
+                }
+                result
+            }
+
+            /// Creates a matrix from its rows.
+            pub const fn from_rows(rows: [[$scalar; C]; R]) -> Self {
+                Self { rows }
+            }
+
+            /// Returns the row at `index`.
+            pub fn row(&self, index: usize) -> [$scalar; C] {
+                self.rows[index]
+            }
+
+            /// Returns the column at `index`.
+            pub fn column(&self, index: usize) -> [$scalar; R] {
+                let mut result = [0.0; R];
+                for (element, row) in result.iter_mut().zip(self.rows.iter()) {
+                    *element = row[index];
+                }
+                result
+            }
+
+            /// Creates a transpose of this matrix, swapping its rows and columns.
+            pub fn transpose(&self) -> $type<C, R> {
+                let mut result = $type::<C, R>::zero();
+                for (r, row) in self.rows.iter().enumerate() {
+                    for (c, element) in row.iter().enumerate() {
+                        result.rows[c][r] = *element;
+                    }
+                }
+                result
+            }
+
+            fn map(mut self, f: impl Fn($scalar) -> $scalar) -> Self {
+                for element in self.rows.iter_mut().flat_map(|row| row.iter_mut()) {
+                    *element = f(*element);
+                }
+                self
+            }
+
+            fn zip(mut self, rhs: &Self, f: impl Fn($scalar, $scalar) -> $scalar) -> Self {
+                for (row, rhs_row) in self.rows.iter_mut().zip(rhs.rows.iter()) {
+                    for (element, rhs_element) in row.iter_mut().zip(rhs_row.iter()) {
+                        *element = f(*element, *rhs_element);
+                    }
+                }
+                self
+            }
+
+            fn all(&self, rhs: &Self, f: impl Fn(&$scalar, &$scalar) -> bool) -> bool {
+                self.rows.iter().flat_map(|row| row.iter())
+                    .zip(rhs.rows.iter().flat_map(|row| row.iter()))
+                    .all(|(lhs, rhs)| f(lhs, rhs))
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Index<(usize, usize)> for $type<R, C> {
+            type Output = $scalar;
+
+            fn index(&self, (row, column): (usize, usize)) -> &$scalar {
+                &self.rows[row][column]
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::IndexMut<(usize, usize)> for $type<R, C> {
+            fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut $scalar {
+                &mut self.rows[row][column]
+            }
+        }
+
+        impl<const R: usize, const K: usize, const C: usize> std::ops::Mul<$type<K, C>> for $type<R, K> {
+            type Output = $type<R, C>;
+
+            fn mul(self, rhs: $type<K, C>) -> $type<R, C> {
+                let mut result = $type::<R, C>::zero();
+                for (result_row, row) in result.rows.iter_mut().zip(self.rows.iter()) {
+                    for (c, element) in result_row.iter_mut().enumerate() {
+                        *element = row.iter().zip(rhs.rows.iter()).map(|(lhs, rhs_row)| lhs * rhs_row[c]).sum();
+                    }
+                }
+                result
+            }
+        }
+
+        impl<const N: usize> std::ops::MulAssign for $type<N, N> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Add for $type<R, C> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                self.zip(&rhs, |lhs, rhs| lhs + rhs)
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::AddAssign for $type<R, C> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Sub for $type<R, C> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.zip(&rhs, |lhs, rhs| lhs - rhs)
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::SubAssign for $type<R, C> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Mul<$scalar> for $type<R, C> {
+            type Output = Self;
+
+            fn mul(self, rhs: $scalar) -> Self {
+                self.map(|element| element * rhs)
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::MulAssign<$scalar> for $type<R, C> {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Div<$scalar> for $type<R, C> {
+            type Output = Self;
+
+            fn div(self, rhs: $scalar) -> Self {
+                self.map(|element| element / rhs)
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::DivAssign<$scalar> for $type<R, C> {
+            fn div_assign(&mut self, rhs: $scalar) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const R: usize, const C: usize> std::ops::Neg for $type<R, C> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.map(|element| -element)
+            }
+        }
+
+        impl<const R: usize, const C: usize> approx::AbsDiffEq for $type<R, C> {
+            type Epsilon = $scalar;
+
+            fn default_epsilon() -> Self::Epsilon {
+                Self::Epsilon::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.all(other, |lhs, rhs| Self::Epsilon::abs_diff_eq(lhs, rhs, epsilon))
+            }
+        }
+
+        impl<const R: usize, const C: usize> approx::RelativeEq for $type<R, C> {
+            fn default_max_relative() -> Self::Epsilon {
+                Self::Epsilon::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                self.all(other, |lhs, rhs| Self::Epsilon::relative_eq(lhs, rhs, epsilon, max_relative))
+            }
+        }
+
+        impl<const R: usize, const C: usize> approx::UlpsEq for $type<R, C> {
+            fn default_max_ulps() -> u32 {
+                Self::Epsilon::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.all(other, |lhs, rhs| Self::Epsilon::ulps_eq(lhs, rhs, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+/// Converts between a named matrix type and the generic matrix of the same shape.
+/// Element `rows[r][c]` of the generic matrix is the field `c{c}{r}` of the named one.
+macro_rules! impl_generic_conversion {
+    ($generic: ident<$rows: literal, $columns: literal>, $named: ident, $([$($field: ident),+]),+) => {
+        impl From<$named> for $generic<$rows, $columns> {
+            fn from(m: $named) -> Self {
+                Self { rows: [$([$(m.$field),+]),+] }
+            }
+        }
+
+        impl From<$generic<$rows, $columns>> for $named {
+            fn from(m: $generic<$rows, $columns>) -> Self {
+                let [$([$($field),+]),+] = m.rows;
+                $named { $($($field),+),+ }
+            }
+        }
+    };
+}
+
+macro_rules! define_generic_matrix_family {
+    ($generic: ident, $matrix1x3: ident, $matrix2x2: ident, $matrix3x3: ident, $matrix4x4: ident) => {
+        impl_generic_conversion!($generic<3, 1>, $matrix1x3, [c00], [c01], [c02]);
+        impl_generic_conversion!($generic<2, 2>, $matrix2x2, [c00, c10], [c01, c11]);
+        impl_generic_conversion!($generic<3, 3>, $matrix3x3, [c00, c10, c20], [c01, c11, c21], [c02, c12, c22]);
+        impl_generic_conversion!($generic<4, 4>, $matrix4x4, [c00, c10, c20, c30], [c01, c11, c21, c31], [c02, c12, c22, c32], [c03, c13, c23, c33]);
+
+        impl $matrix4x4 {
+            /// Returns the affine part of this matrix as 4 rows of 3, dropping the last column.
+            /// This is the compact layout for shaders that multiply row vectors, as this crate does.
+            pub fn to_affine_rows(&self) -> $generic<4, 3> {
+                $generic::from_rows([
+                    [self.c00, self.c10, self.c20],
+                    [self.c01, self.c11, self.c21],
+                    [self.c02, self.c12, self.c22],
+                    [self.c03, self.c13, self.c23],
+                ])
+            }
+
+            /// Returns the affine part of this matrix as 3 rows of 4, which is the transpose of `to_affine_rows`.
+            /// This is the compact layout for shaders that multiply column vectors, such as skinning palettes.
+            pub fn to_affine_columns(&self) -> $generic<3, 4> {
+                self.to_affine_rows().transpose()
+            }
+        }
+
+        impl From<$generic<4, 3>> for $matrix4x4 {
+            /// Restores an affine matrix from the layout of `to_affine_rows`.
+            fn from(m: $generic<4, 3>) -> Self {
+                let [[c00, c10, c20], [c01, c11, c21], [c02, c12, c22], [c03, c13, c23]] = m.rows;
+                $matrix4x4 {
+                    c00, c10, c20, c30: 0.0,
+                    c01, c11, c21, c31: 0.0,
+                    c02, c12, c22, c32: 0.0,
+                    c03, c13, c23, c33: 1.0,
+                }
+            }
+        }
+
+        impl From<$generic<3, 4>> for $matrix4x4 {
+            /// Restores an affine matrix from the layout of `to_affine_columns`.
+            fn from(m: $generic<3, 4>) -> Self {
+                $matrix4x4::from(m.transpose())
+            }
+        }
+    };
+}
+
+define_generic_matrix!(
+    /// A matrix of any size, with `R` rows and `C` columns, stored row by row.
+    ///
+    /// Multiplication is only defined between compatible shapes, so an `R x K` matrix can only be multiplied by a `K x C` one:
+    /// ```compile_fail
+    /// use ezcgmath::matrix::Matrix;
+    /// let _ = Matrix::<2, 3>::zero() * Matrix::<2, 3>::zero();
+    /// ```
+    ///
+    /// The named matrix types convert to and from the generic matrix of the same shape. As `Matrix1x3` is a single
+    /// column of three rows, it converts to `Matrix<3, 1>`.
+    Matrix, Scalar
+);
+define_generic_matrix!(
+    /// A matrix of any size, with double precision. See `Matrix` for details.
+    DMatrix, f64
+);
+
+define_generic_matrix_family!(Matrix, Matrix1x3, Matrix2x2, Matrix3x3, Matrix4x4);
+define_generic_matrix_family!(DMatrix, DMatrix1x3, DMatrix2x2, DMatrix3x3, DMatrix4x4);
+
+/// A matrix with 2 rows and 3 columns.
+pub type Matrix2x3 = Matrix<2, 3>;
+/// A matrix with 3 rows and 2 columns.
+pub type Matrix3x2 = Matrix<3, 2>;
+/// A matrix with 3 rows and 4 columns, such as the compact affine matrices of a skinning palette.
+pub type Matrix3x4 = Matrix<3, 4>;
+/// A matrix with 4 rows and 3 columns, such as the affine part of a `Matrix4x4`.
+pub type Matrix4x3 = Matrix<4, 3>;
+
+/// A matrix with 2 rows and 3 columns, with double precision.
+pub type DMatrix2x3 = DMatrix<2, 3>;
+/// A matrix with 3 rows and 2 columns, with double precision.
+pub type DMatrix3x2 = DMatrix<3, 2>;
+/// A matrix with 3 rows and 4 columns, with double precision.
+pub type DMatrix3x4 = DMatrix<3, 4>;
+/// A matrix with 4 rows and 3 columns, with double precision.
+pub type DMatrix4x3 = DMatrix<4, 3>;
+
+impl<const R: usize, const C: usize> Matrix<R, C> {
+    /// Converts to double precision. This conversion is lossless.
+    pub fn to_f64(&self) -> DMatrix<R, C> {
+        let mut result = DMatrix::zero();
+        for (row, single_row) in result.rows.iter_mut().zip(self.rows.iter()) {
+            for (element, single) in row.iter_mut().zip(single_row.iter()) {
+                *element = *single as f64;
+            }
+        }
+        result
+    }
+}
+
+impl<const R: usize, const C: usize> DMatrix<R, C> {
+    /// Converts to single precision, rounding every element to the nearest `f32`.
+    pub fn to_f32(&self) -> Matrix<R, C> {
+        let mut result = Matrix::zero();
+        for (row, double_row) in result.rows.iter_mut().zip(self.rows.iter()) {
+            for (element, double) in row.iter_mut().zip(double_row.iter()) {
+                *element = *double as f32;
+            }
+        }
+        result
+    }
+}
+
+impl<const R: usize, const C: usize> From<Matrix<R, C>> for DMatrix<R, C> {
+    fn from(value: Matrix<R, C>) -> Self {
+        value.to_f64()
+    }
+}
@@ -1,17 +1,31 @@
 use crate::Scalar;
 
-/// A 1 x 3 Matrix.
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct Matrix1x3 {
-    pub c00: Scalar,
-    pub c01: Scalar,
-    pub c02: Scalar,
+macro_rules! define_matrix1x3 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub c00: $scalar,
+            pub c01: $scalar,
+            pub c02: $scalar,
+        }
+        impl_add_self!($type, c00, c01, c02);
+        impl_sub_self!($type, c00, c01, c02);
+        impl_mul_scalar!($type; $scalar; c00, c01, c02);
+        impl_div_scalar!($type; $scalar; c00, c01, c02);
+        impl_negate_self!($type, c00, c01, c02);
+        impl_approx!($type; $scalar; c00, c01, c02);
+    };
 }
-impl_add_self!(Matrix1x3, c00, c01, c02);
-impl_sub_self!(Matrix1x3, c00, c01, c02);
-impl_mul_scalar!(Matrix1x3, c00, c01, c02);
-impl_approx!(Matrix1x3, c00, c01, c02);
 
-impl Matrix1x3 {
-}
+define_matrix1x3!(
+    /// A 1 x 3 Matrix.
+    Matrix1x3, Scalar
+);
+define_matrix1x3!(
+    /// A 1 x 3 Matrix, with double precision.
+    DMatrix1x3, f64
+);
+
+impl_precision_conversion!(Matrix1x3, DMatrix1x3, c00, c01, c02);
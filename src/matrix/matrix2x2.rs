@@ -1,55 +1,156 @@
-use crate::Scalar;
-
-/// A 2 x 2 Matrix.
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct Matrix2x2 {
-    pub c00: Scalar, pub c10: Scalar,
-    pub c01: Scalar, pub c11: Scalar,
-}
-impl_add_self!(Matrix2x2, c00, c10, c01, c11);
-impl_sub_self!(Matrix2x2, c00, c10, c01, c11);
-impl_mul_scalar!(Matrix2x2, c00, c10, c01, c11);
-impl_approx!(Matrix2x2, c00, c10, c01, c11);
-
-impl Matrix2x2 {
-    /// Creates an instance of a 2x2 identity matrix.
-    pub const fn identity() -> Self {
-        Self {
-            c00: 1.0, c10: 0.0,
-            c01: 0.0, c11: 1.0,
+use crate::{AngleScalar, Degrees, Error, Scalar};
+use crate::vector::{Vector2, DVector2};
+
+macro_rules! define_matrix2x2 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector2: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub c00: $scalar, pub c10: $scalar,
+            pub c01: $scalar, pub c11: $scalar,
         }
-    }
+        impl_add_self!($type, c00, c10, c01, c11);
+        impl_sub_self!($type, c00, c10, c01, c11);
+        impl_mul_scalar!($type; $scalar; c00, c10, c01, c11);
+        impl_div_scalar!($type; $scalar; c00, c10, c01, c11);
+        impl_negate_self!($type, c00, c10, c01, c11);
+        impl_approx!($type; $scalar; c00, c10, c01, c11);
 
-    // Calculates the determinant for this matrix.
-    pub fn determinant(&self) -> Scalar {
-        self.c00 * self.c11 - self.c10 * self.c01
-    }
-}
+        impl $type {
+            /// Creates an instance of a 2x2 identity matrix.
+            pub const fn identity() -> Self {
+                Self {
+                    c00: 1.0, c10: 0.0,
+                    c01: 0.0, c11: 1.0,
+                }
+            }
+
+            /// Calculates the determinant for this matrix.
+            pub fn determinant(&self) -> $scalar {
+                self.c00 * self.c11 - self.c10 * self.c01
+            }
+
+            /// Creates a rotation by the given angle.
+            ///
+            /// This matches `Matrix3x3::from_rotation_z`, so with Y as up a positive angle rotates clockwise.
+            pub fn from_angle(angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                Self {
+                    c00: c, c10: -s,
+                    c01: s, c11: c,
+                }
+            }
+
+            #[doc = concat!("Creates a non-uniform scaling matrix from a `", stringify!($vector2), "`.")]
+            pub const fn from_nonuniform_scale(scale: &$vector2) -> Self {
+                Self {
+                    c00: scale.x, c10: 0.0,
+                    c01: 0.0,     c11: scale.y,
+                }
+            }
+
+            /// Creates a uniform scaling matrix from a scalar.
+            pub const fn from_scale(scale: $scalar) -> Self {
+                Self {
+                    c00: scale, c10: 0.0,
+                    c01: 0.0,   c11: scale,
+                }
+            }
+
+            /// Creates a transpose of this matrix.
+            pub fn transpose(&self) -> $type {
+                $type {
+                    c00: self.c00, c10: self.c01,
+                    c01: self.c10, c11: self.c11,
+                }
+            }
+
+            /// Calculates the sum of the elements on the main diagonal.
+            pub fn trace(&self) -> $scalar {
+                self.c00 + self.c11
+            }
 
-impl std::ops::Mul for Matrix2x2 {
-    type Output = Matrix2x2;
+            /// Calculates the Inverse matrix for this matrix.
+            /// The determinant is not checked, so a singular matrix results in inf/NaN elements. Use `try_inverse` if this is a possibility.
+            pub fn inverse(&self) -> $type {
+                let adjugate = $type {
+                    c00: self.c11,  c10: -self.c10,
+                    c01: -self.c01, c11: self.c00,
+                };
+                adjugate / self.determinant()
+            }
 
-    fn mul(self, rhs: Matrix2x2) -> Matrix2x2 {
-        Matrix2x2 {
-            c00: self.c00 * rhs.c00 + self.c10 * rhs.c01,
-            c10: self.c00 * rhs.c10 + self.c10 * rhs.c11,
+            /// Calculates the Inverse matrix for this matrix, unless it is singular.
+            ///
+            /// Unlike `inverse`, this returns `Error::SingularMatrix` rather than a matrix of inf/NaN
+            /// when the absolute determinant is less than or equal to `tolerance`.
+            pub fn try_inverse(&self, tolerance: $scalar) -> Result<$type, Error> {
+                let determinant = self.determinant();
+                if determinant.is_nan() || determinant.abs() <= tolerance {
+                    return Err(Error::SingularMatrix);
+                }
+                Ok(self.inverse())
+            }
 
-            c01: self.c01 * rhs.c00 + self.c11 * rhs.c01,
-            c11: self.c01 * rhs.c10 + self.c11 * rhs.c11,
+            /// Calculates the Inverse matrix for this matrix, or `None` if it is singular. See `try_inverse`.
+            pub fn checked_inverse(&self, tolerance: $scalar) -> Option<$type> {
+                self.try_inverse(tolerance).ok()
+            }
+
+            /// Calculates the eigenvalues of this matrix, largest first.
+            ///
+            /// Returns `None` if they are complex, which happens for rotations. The eigenvalues of a symmetric matrix,
+            /// such as an inertia tensor, are always real.
+            pub fn eigenvalues(&self) -> Option<($scalar, $scalar)> {
+                let half_trace = self.trace() * 0.5;
+                // Equal to half_trace² - determinant, but without the cancellation, and never negative when symmetric.
+                let half_difference = (self.c00 - self.c11) * 0.5;
+                let discriminant = half_difference * half_difference + self.c10 * self.c01;
+                if discriminant < 0.0 {
+                    return None;
+                }
+                let offset = discriminant.sqrt();
+                Some((half_trace + offset, half_trace - offset))
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign for Matrix2x2 {
-    fn mul_assign(&mut self, rhs: Matrix2x2) {
-        let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01;
-        let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11;
+        impl std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    c00: self.c00 * rhs.c00 + self.c10 * rhs.c01,
+                    c10: self.c00 * rhs.c10 + self.c10 * rhs.c11,
+
+                    c01: self.c01 * rhs.c00 + self.c11 * rhs.c01,
+                    c11: self.c01 * rhs.c10 + self.c11 * rhs.c11,
+                }
+            }
+        }
 
-        let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01;
-        let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11;
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01;
+                let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11;
 
-        self.c00 = c00; self.c10 = c10;
-        self.c01 = c01; self.c11 = c11;
-    }
+                let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01;
+                let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11;
+
+                self.c00 = c00; self.c10 = c10;
+                self.c01 = c01; self.c11 = c11;
+            }
+        }
+    };
 }
+
+define_matrix2x2!(
+    /// A 2 x 2 Matrix.
+    Matrix2x2, Scalar, Vector2
+);
+define_matrix2x2!(
+    /// A 2 x 2 Matrix, with double precision.
+    DMatrix2x2, f64, DVector2
+);
+
+impl_precision_conversion!(Matrix2x2, DMatrix2x2, c00, c10, c01, c11);
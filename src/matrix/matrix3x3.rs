@@ -1,178 +1,341 @@
-use super::{Matrix2x2, Matrix1x3};
-use crate::Scalar;
+use super::{Matrix1x3, Matrix2x2, DMatrix1x3, DMatrix2x2};
+use crate::{AngleScalar, Degrees, Error, Scalar};
 use crate::vector::*;
 
-/// A 3 x 3 Matrix.
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct Matrix3x3 {
-    pub c00: Scalar, pub c10: Scalar, pub c20: Scalar,
-    pub c01: Scalar, pub c11: Scalar, pub c21: Scalar,
-    pub c02: Scalar, pub c12: Scalar, pub c22: Scalar,
-}
-impl_add_self!(Matrix3x3, c00, c10, c20, c01, c11, c21, c02, c12, c22);
-impl_sub_self!(Matrix3x3, c00, c10, c20, c01, c11, c21, c02, c12, c22);
-impl_mul_scalar!(Matrix3x3, c00, c10, c20, c01, c11, c21, c02, c12, c22);
-impl_approx!(Matrix3x3, c00, c10, c20, c01, c11, c21, c02, c12, c22);
-
-impl Matrix3x3 {
-    /// Creates an instance of a 3x3 identity matrix.
-    pub const fn identity() -> Self {
-        Self {
-            c00: 1.0, c10: 0.0, c20: 0.0,
-            c01: 0.0, c11: 1.0, c21: 0.0,
-            c02: 0.0, c12: 0.0, c22: 1.0,
-        }
-    }
-
-    /// Compiles a matrix of minors for this matrix.
-    pub fn matrix_of_minors(&self) -> Matrix3x3 {
-        let c00 = Matrix2x2 {
-            c00: self.c11, c10: self.c21,
-            c01: self.c12, c11: self.c22
-        }.determinant();
-        let c10 = Matrix2x2 {
-            c00: self.c01, c10: self.c21,
-            c01: self.c02, c11: self.c22,
-        }.determinant();
-        let c20 = Matrix2x2 {
-            c00: self.c01, c10: self.c11,
-            c01: self.c02, c11: self.c12,
-        }.determinant();
-
-        let c01 = Matrix2x2 {
-            c00: self.c10, c10: self.c20,
-            c01: self.c12, c11: self.c22
-        }.determinant();
-        let c11 = Matrix2x2 {
-            c00: self.c00, c10: self.c20,
-            c01: self.c02, c11: self.c22,
-        }.determinant();
-        let c21 = Matrix2x2 {
-            c00: self.c00, c10: self.c10,
-            c01: self.c02, c11: self.c12,
-        }.determinant();
-        
-        let c02 = Matrix2x2 {
-            c00: self.c10, c10: self.c20,
-            c01: self.c11, c11: self.c21
-        }.determinant();
-        let c12 = Matrix2x2 {
-            c00: self.c00, c10: self.c20,
-            c01: self.c01, c11: self.c21,
-        }.determinant();
-        let c22 = Matrix2x2 {
-            c00: self.c00, c10: self.c10,
-            c01: self.c01, c11: self.c11,
-        }.determinant();
-
-        Matrix3x3 {
-            c00, c10, c20,
-            c01, c11, c21,
-            c02, c12, c22,
-        }
-    }
-
-    /// Compiles a matrix of cofactors for this matrix.
-    pub fn matrix_of_cofactors(&self) -> Matrix3x3 {
-        Matrix3x3 {
-            c00:  self.c00, c10: -self.c10, c20:  self.c20,
-            c01: -self.c01, c11:  self.c11, c21: -self.c21,
-            c02:  self.c02, c12: -self.c12, c22:  self.c22,
+macro_rules! define_matrix3x3 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $matrix2x2: ident, $matrix1x3: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub c00: $scalar, pub c10: $scalar, pub c20: $scalar,
+            pub c01: $scalar, pub c11: $scalar, pub c21: $scalar,
+            pub c02: $scalar, pub c12: $scalar, pub c22: $scalar,
         }
-    }
-
-    /// Returns a new matrix with the elements transposed.
-    pub fn transpose(&self) -> Matrix3x3 {
-        Matrix3x3 {
-            c00: self.c00, c10: self.c01, c20: self.c02,
-            c01: self.c10, c11: self.c11, c21: self.c12,
-            c02: self.c20, c12: self.c21, c22: self.c22,
-        }
-    }
-
-    /// Calculates the determinant for this matrix.
-    pub fn determinant(&self) -> f32 {
-        let minors = self.matrix_of_minors();
-        let cofactors = minors.matrix_of_cofactors();
-        self.c00 * cofactors.c00 + self.c10 * cofactors.c10 + self.c20 * cofactors.c20
-    }
-
-    /// Calculates the Inverse matrix for this matrix.
-    pub fn inverse(&self) -> Matrix3x3 {
-        let minors = self.matrix_of_minors();
-        let cofactors = minors.matrix_of_cofactors();
-        let adjugate = cofactors.transpose();
-        let determinant = self.determinant();
-        adjugate * (1.0 / determinant)
-    }
-
-    /// Creates a new so called "look at" rotation. This rotation will point in the forward direction
-    /// with the given up direction.
-    ///
-    /// As a reminder, this will create a left-handed rotation matrix.
-    /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
-    pub fn from_look_at(mut forward: Vector3, mut up: Vector3) -> Self {
-        forward.normalize();
-        up.normalize();
-        let right = up.cross(&forward);
-
-        Matrix3x3 {
-            c00: right.x,   c10: right.y,   c20: right.z,
-            c01: up.x,      c11: up.y,      c21: up.z,
-            c02: forward.x, c12: forward.y, c22: forward.z,
+        impl_add_self!($type, c00, c10, c20, c01, c11, c21, c02, c12, c22);
+        impl_sub_self!($type, c00, c10, c20, c01, c11, c21, c02, c12, c22);
+        impl_mul_scalar!($type; $scalar; c00, c10, c20, c01, c11, c21, c02, c12, c22);
+        impl_div_scalar!($type; $scalar; c00, c10, c20, c01, c11, c21, c02, c12, c22);
+        impl_negate_self!($type, c00, c10, c20, c01, c11, c21, c02, c12, c22);
+        impl_approx!($type; $scalar; c00, c10, c20, c01, c11, c21, c02, c12, c22);
+
+        impl $type {
+            /// Creates an instance of a 3x3 identity matrix.
+            pub const fn identity() -> Self {
+                Self {
+                    c00: 1.0, c10: 0.0, c20: 0.0,
+                    c01: 0.0, c11: 1.0, c21: 0.0,
+                    c02: 0.0, c12: 0.0, c22: 1.0,
+                }
+            }
+
+            /// Creates a rotation of a given angle around a given axis, which is expected to be normalized.
+            ///
+            /// This is the same rotation as `Quaternion::from_axis_angle` creates.
+            pub fn from_axis_angle(axis: &$vector3, angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                let t = 1.0 - c;
+                let (x, y, z) = (axis.x, axis.y, axis.z);
+                Self {
+                    c00: c + t * x * x,     c10: t * x * y - s * z, c20: t * x * z + s * y,
+                    c01: t * x * y + s * z, c11: c + t * y * y,     c21: t * y * z - s * x,
+                    c02: t * x * z - s * y, c12: t * y * z + s * x, c22: c + t * z * z,
+                }
+            }
+
+            /// Creates a rotation of a given angle around the X axis.
+            pub fn from_rotation_x(angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                Self {
+                    c00: 1.0, c10: 0.0, c20: 0.0,
+                    c01: 0.0, c11: c,   c21: -s,
+                    c02: 0.0, c12: s,   c22: c,
+                }
+            }
+
+            /// Creates a rotation of a given angle around the Y axis.
+            pub fn from_rotation_y(angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                Self {
+                    c00: c,   c10: 0.0, c20: s,
+                    c01: 0.0, c11: 1.0, c21: 0.0,
+                    c02: -s,  c12: 0.0, c22: c,
+                }
+            }
+
+            /// Creates a rotation of a given angle around the Z axis.
+            pub fn from_rotation_z(angle: Degrees) -> Self {
+                let (s, c) = <$scalar as AngleScalar>::radians(angle).sin_cos();
+                Self {
+                    c00: c,   c10: -s,  c20: 0.0,
+                    c01: s,   c11: c,   c21: 0.0,
+                    c02: 0.0, c12: 0.0, c22: 1.0,
+                }
+            }
+
+            /// Creates a matrix that maps the X, Y and Z axes onto the given right, up and forward directions.
+            ///
+            /// The directions are used as they are, so they should be normalized and perpendicular for a pure rotation.
+            pub const fn from_basis(right: &$vector3, up: &$vector3, forward: &$vector3) -> Self {
+                Self {
+                    c00: right.x,   c10: right.y,   c20: right.z,
+                    c01: up.x,      c11: up.y,      c21: up.z,
+                    c02: forward.x, c12: forward.y, c22: forward.z,
+                }
+            }
+
+            /// Creates a tangent frame for a surface with the given normal, which is expected to be normalized.
+            ///
+            /// The rows are the tangent, the bitangent and the normal, so the matrix maps Z onto the normal, as
+            /// normal maps and decal projections expect. The tangents are chosen with `orthonormal_basis`, so the
+            /// frame is valid for every normal, but its rotation around the normal is arbitrary.
+            pub fn from_normal(normal: &$vector3) -> Self {
+                let (tangent, bitangent) = normal.orthonormal_basis();
+                Self::from_basis(&tangent, &bitangent, normal)
+            }
+
+            /// Creates a reflection through the plane that passes through the origin with the given normal.
+            pub fn from_reflection(normal: &$vector3) -> Self {
+                let mut n = *normal;
+                n.normalize();
+                Self {
+                    c00: 1.0 - 2.0 * n.x * n.x, c10: -2.0 * n.x * n.y,      c20: -2.0 * n.x * n.z,
+                    c01: -2.0 * n.y * n.x,      c11: 1.0 - 2.0 * n.y * n.y, c21: -2.0 * n.y * n.z,
+                    c02: -2.0 * n.z * n.x,      c12: -2.0 * n.z * n.y,      c22: 1.0 - 2.0 * n.z * n.z,
+                }
+            }
+
+            /// Creates a shear. Each parameter is the amount that the first axis is offset by, per unit along the second.
+            /// For example, `x_by_y` is how far a point moves along X for each unit of Y.
+            pub const fn from_shear(x_by_y: $scalar, x_by_z: $scalar, y_by_x: $scalar, y_by_z: $scalar, z_by_x: $scalar, z_by_y: $scalar) -> Self {
+                Self {
+                    c00: 1.0,    c10: y_by_x, c20: z_by_x,
+                    c01: x_by_y, c11: 1.0,    c21: z_by_y,
+                    c02: x_by_z, c12: y_by_z, c22: 1.0,
+                }
+            }
+
+            /// Compiles a matrix of minors for this matrix.
+            pub fn matrix_of_minors(&self) -> $type {
+                let c00 = $matrix2x2 {
+                    c00: self.c11, c10: self.c21,
+                    c01: self.c12, c11: self.c22
+                }.determinant();
+                let c10 = $matrix2x2 {
+                    c00: self.c01, c10: self.c21,
+                    c01: self.c02, c11: self.c22,
+                }.determinant();
+                let c20 = $matrix2x2 {
+                    c00: self.c01, c10: self.c11,
+                    c01: self.c02, c11: self.c12,
+                }.determinant();
+
+                let c01 = $matrix2x2 {
+                    c00: self.c10, c10: self.c20,
+                    c01: self.c12, c11: self.c22
+                }.determinant();
+                let c11 = $matrix2x2 {
+                    c00: self.c00, c10: self.c20,
+                    c01: self.c02, c11: self.c22,
+                }.determinant();
+                let c21 = $matrix2x2 {
+                    c00: self.c00, c10: self.c10,
+                    c01: self.c02, c11: self.c12,
+                }.determinant();
+
+                let c02 = $matrix2x2 {
+                    c00: self.c10, c10: self.c20,
+                    c01: self.c11, c11: self.c21
+                }.determinant();
+                let c12 = $matrix2x2 {
+                    c00: self.c00, c10: self.c20,
+                    c01: self.c01, c11: self.c21,
+                }.determinant();
+                let c22 = $matrix2x2 {
+                    c00: self.c00, c10: self.c10,
+                    c01: self.c01, c11: self.c11,
+                }.determinant();
+
+                $type {
+                    c00, c10, c20,
+                    c01, c11, c21,
+                    c02, c12, c22,
+                }
+            }
+
+            /// Compiles a matrix of cofactors for this matrix.
+            pub fn matrix_of_cofactors(&self) -> $type {
+                $type {
+                    c00:  self.c00, c10: -self.c10, c20:  self.c20,
+                    c01: -self.c01, c11:  self.c11, c21: -self.c21,
+                    c02:  self.c02, c12: -self.c12, c22:  self.c22,
+                }
+            }
+
+            /// Returns a new matrix with the elements transposed.
+            pub fn transpose(&self) -> $type {
+                $type {
+                    c00: self.c00, c10: self.c01, c20: self.c02,
+                    c01: self.c10, c11: self.c11, c21: self.c12,
+                    c02: self.c20, c12: self.c21, c22: self.c22,
+                }
+            }
+
+            /// Calculates the determinant for this matrix.
+            pub fn determinant(&self) -> $scalar {
+                let minors = self.matrix_of_minors();
+                let cofactors = minors.matrix_of_cofactors();
+                self.c00 * cofactors.c00 + self.c10 * cofactors.c10 + self.c20 * cofactors.c20
+            }
+
+            /// Calculates the Inverse matrix for this matrix.
+            /// The determinant is not checked, so a singular matrix results in inf/NaN elements. Use `try_inverse` if this is a possibility.
+            pub fn inverse(&self) -> $type {
+                let minors = self.matrix_of_minors();
+                let cofactors = minors.matrix_of_cofactors();
+                let adjugate = cofactors.transpose();
+                let determinant = self.determinant();
+                adjugate * (1.0 / determinant)
+            }
+
+            /// Calculates the Inverse matrix for this matrix, unless it is singular.
+            ///
+            /// Unlike `inverse`, this returns `Error::SingularMatrix` rather than a matrix of inf/NaN
+            /// when the absolute determinant is less than or equal to `tolerance`.
+            pub fn try_inverse(&self, tolerance: $scalar) -> Result<$type, Error> {
+                let determinant = self.determinant();
+                if determinant.is_nan() || determinant.abs() <= tolerance {
+                    return Err(Error::SingularMatrix);
+                }
+                let adjugate = self.matrix_of_minors().matrix_of_cofactors().transpose();
+                Ok(adjugate * (1.0 / determinant))
+            }
+
+            /// Calculates the Inverse matrix for this matrix, or `None` if it is singular. See `try_inverse`.
+            pub fn checked_inverse(&self, tolerance: $scalar) -> Option<$type> {
+                self.try_inverse(tolerance).ok()
+            }
+
+            /// Makes the rows of this matrix perpendicular and of unit length with the Gram-Schmidt process,
+            /// keeping the direction of the first row, and the plane of the first two rows.
+            ///
+            /// This is cheap, but favours the first row. `nearest_rotation` spreads the correction evenly across all rows.
+            /// The rows must be linearly independent, otherwise the result is filled with NaN.
+            pub fn orthonormalize(&self) -> $type {
+                let mut x = $vector3::new(self.c00, self.c10, self.c20);
+                x.normalize();
+                let mut y = $vector3::new(self.c01, self.c11, self.c21);
+                y = y - x * x.dot(&y);
+                y.normalize();
+                let mut z = $vector3::new(self.c02, self.c12, self.c22);
+                z = z - x * x.dot(&z);
+                z = z - y * y.dot(&z);
+                z.normalize();
+                $type::from_basis(&x, &y, &z)
+            }
+
+            /// Returns true if every row is of unit length and perpendicular to the others, to within `epsilon`.
+            pub fn is_orthonormal(&self, epsilon: $scalar) -> bool {
+                use approx::AbsDiffEq;
+                (*self * self.transpose()).abs_diff_eq(&$type::identity(), epsilon)
+            }
+
+            /// Returns true if this matrix is orthonormal and has a determinant of +1 to within `epsilon`,
+            /// which means that it is a rotation without any scale, skew or reflection.
+            pub fn is_rotation(&self, epsilon: $scalar) -> bool {
+                self.is_orthonormal(epsilon) && (self.determinant() - 1.0).abs() <= epsilon
+            }
+
+            /// Creates a new so called "look at" rotation. This rotation will point in the forward direction
+            /// with the given up direction.
+            ///
+            /// The up direction only needs to be roughly perpendicular to forward: it is tilted until it is, so
+            /// the result is always a rotation. If it is parallel to forward, such as a camera looking straight
+            /// down with the Y axis as up, the Z axis is used as up instead, or the Y axis if forward is along Z.
+            /// Forward must not have a length of zero.
+            ///
+            /// As a reminder, this will create a left-handed rotation matrix.
+            /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
+            pub fn from_look_at(mut forward: $vector3, mut up: $vector3) -> Self {
+                forward.normalize();
+                up.normalize();
+                let mut right = up.cross(&forward);
+                if right.try_normalize(<$scalar>::EPSILON.sqrt()).is_err() {
+                    let fallback = if forward.z.abs() < 0.9 { $vector3::unit_z() } else { $vector3::unit_y() };
+                    right = fallback.cross(&forward);
+                    right.normalize();
+                }
+                let up = forward.cross(&right);
+
+                $type {
+                    c00: right.x,   c10: right.y,   c20: right.z,
+                    c01: up.x,      c11: up.y,      c21: up.z,
+                    c02: forward.x, c12: forward.y, c22: forward.z,
+                }
+            }
         }
-    }
-}
 
-impl std::ops::Mul for Matrix3x3 {
-    type Output = Matrix3x3;
+        impl std::ops::Mul for $type {
+            type Output = $type;
 
-    fn mul(self, rhs: Matrix3x3) -> Matrix3x3 {
-        Matrix3x3 {
-            c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02,
-            c10: self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12,
-            c20: self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22,
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02,
+                    c10: self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12,
+                    c20: self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22,
 
-            c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02,
-            c11: self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12,
-            c21: self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22,
+                    c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02,
+                    c11: self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12,
+                    c21: self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22,
 
-            c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02,
-            c12: self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12,
-            c22: self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22,
+                    c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02,
+                    c12: self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12,
+                    c22: self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22,
+                }
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign for Matrix3x3 {
-    fn mul_assign(&mut self, rhs: Matrix3x3) {
-        let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02;
-        let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12;
-        let c20 = self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22;
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02;
+                let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12;
+                let c20 = self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22;
 
-        let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02;
-        let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12;
-        let c21 = self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22;
+                let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02;
+                let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12;
+                let c21 = self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22;
 
-        let c02 = self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02;
-        let c12 = self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12;
-        let c22 = self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22;
+                let c02 = self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02;
+                let c12 = self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12;
+                let c22 = self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22;
 
-        self.c00 = c00; self.c10 = c10; self.c20 = c20;
-        self.c01 = c01; self.c11 = c11; self.c21 = c21;
-        self.c02 = c02; self.c12 = c12; self.c22 = c22;
-    }
-}
+                self.c00 = c00; self.c10 = c10; self.c20 = c20;
+                self.c01 = c01; self.c11 = c11; self.c21 = c21;
+                self.c02 = c02; self.c12 = c12; self.c22 = c22;
+            }
+        }
 
-impl std::ops::Mul<Matrix1x3> for Matrix3x3 {
-    type Output = Matrix1x3;
+        impl std::ops::Mul<$matrix1x3> for $type {
+            type Output = $matrix1x3;
 
-    fn mul(self, rhs: Matrix1x3) -> Matrix1x3 {
-        Matrix1x3 {
-            c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02,
-            c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02,
-            c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02,
+            fn mul(self, rhs: $matrix1x3) -> $matrix1x3 {
+                $matrix1x3 {
+                    c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02,
+                    c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02,
+                    c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02,
+                }
+            }
         }
-    }
+    };
 }
+
+define_matrix3x3!(
+    /// A 3 x 3 Matrix.
+    Matrix3x3, Scalar, Vector3, Matrix2x2, Matrix1x3
+);
+define_matrix3x3!(
+    /// A 3 x 3 Matrix, with double precision.
+    DMatrix3x3, f64, DVector3, DMatrix2x2, DMatrix1x3
+);
+
+impl_precision_conversion!(Matrix3x3, DMatrix3x3, c00, c10, c20, c01, c11, c21, c02, c12, c22);
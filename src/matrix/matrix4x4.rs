@@ -1,341 +1,556 @@
-use crate::{Degrees, Radians, Scalar};
+use crate::{AngleScalar, Degrees, Error, Scalar};
 use crate::vector::*;
-use crate::quaternion::Quaternion;
-use super::Matrix3x3;
-
-/// A 4 x 4 Matrix.
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct Matrix4x4 {
-    pub c00: Scalar, pub c10: Scalar, pub c20: Scalar, pub c30: Scalar,
-    pub c01: Scalar, pub c11: Scalar, pub c21: Scalar, pub c31: Scalar,
-    pub c02: Scalar, pub c12: Scalar, pub c22: Scalar, pub c32: Scalar,
-    pub c03: Scalar, pub c13: Scalar, pub c23: Scalar, pub c33: Scalar,
-}
-impl_add_self!(Matrix4x4, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
-impl_sub_self!(Matrix4x4, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
-impl_mul_scalar!(Matrix4x4, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
-impl_approx!(Matrix4x4, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
-
-impl Matrix4x4 {
-    pub const fn identity() -> Self {
-        Self {
-            c00: 1.0, c10: 0.0, c20: 0.0, c30: 0.0,
-            c01: 0.0, c11: 1.0, c21: 0.0, c31: 0.0,
-            c02: 0.0, c12: 0.0, c22: 1.0, c32: 0.0,
-            c03: 0.0, c13: 0.0, c23: 0.0, c33: 1.0,
-        }
-    }
-
-    /// Constructs a new perspective projection. As a reminder, this will create a left-handed perspective matrix.
-    /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
-    ///
-    /// The parameters follow convention as far as I am aware, but to clarify:
-    /// - `fovy` corresponds to the width of the field of view visible (or how far out you can hold your arms).
-    /// - `aspect ratio` is the ratio of width to height for the screen (so aspect_ratio = screen_width / screen_height).
-    /// - `near_plane` defines the start of the clipping volume for the camera along the z (the minimum distance at which objects can be rendered).
-    /// - `far_plane` defines the end of the clipping volume for the camera along the z (the maximum distance at which objects can be rendered).
-    pub fn new_perspective_projection(fov: Degrees, aspect_ratio: Scalar, near_plane: Scalar, far_plane: Scalar) -> Self {
-        assert!(aspect_ratio != 0.0);
-        assert!(near_plane - far_plane != 0.0);
-        let mut result = Self::default();
-        let x_scale = 2.0 / Radians::from(fov).0.tan();
-        result.c00 = x_scale;
-        result.c11 = x_scale / aspect_ratio;
-        result.c22 = far_plane / (far_plane - near_plane);
-        result.c32 = 1.0;
-        result.c33 = near_plane * far_plane / (near_plane - far_plane);
-        result
-    }
-
-    /// Constructs a new orthographic projection. As a reminder, this will create a left-handed orthographic matrix.
-    /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
-    ///
-    /// I opted for simple parameters for this method, so you can create any size or shape orthographic bounding volume you desire.
-    /// However, my advice would be for your code to define an `orthographic_size` variable somehow. You can then convert to top/bottom/left/right as follows:
-    /// ```
-    /// struct OrthoBounds {
-    ///     top: f32,
-    ///     bottom: f32,
-    ///     left: f32,
-    ///     right: f32,
-    /// }
-    ///
-    /// fn get_ortho_bounds(orthographic_size: f32, aspect_ratio: f32) -> OrthoBounds {
-    ///     OrthoBounds {
-    ///         top: 0.5 * orthographic_size,
-    ///         bottom: -0.5 * orthographic_size,
-    ///         left: -0.5 * orthographic_size * aspect_ratio,
-    ///         right: 0.5 * orthographic_size * aspect_ratio,
-    ///     }
-    /// }
-    /// ```
-    /// If you choose not to use the above method, bear in mind that `top - bottom` and `right - left` should **never** equal 0. This will cause a panic.
-    pub fn new_orthographic_projection(top: Scalar, bottom: Scalar, left: Scalar, right: Scalar, near_plane: Scalar, far_plane: Scalar) -> Self {
-        assert!(top - bottom != 0.0);
-        assert!(left - right != 0.0);
-        assert!(near_plane - far_plane != 0.0);
-        let mut result = Self::default();
-        result.c00 = 2.0 / (right - left);
-        result.c11 = 2.0 / (top - bottom);
-        result.c22 = 1.0 / (far_plane - near_plane);
-        result.c23 = -near_plane / (far_plane - near_plane);
-        result.c33 = 1.0;
-        result
-    }
-
-    /// Creates a translation matrix from a `Vector3`.
-    pub const fn from_translation(translation: &Vector3) -> Self {
-        Self {
-            c00: 1.0, c10: 0.0, c20: 0.0, c30: 0.0,
-            c01: 0.0, c11: 1.0, c21: 0.0, c31: 0.0,
-            c02: 0.0, c12: 0.0, c22: 1.0, c32: 0.0,
-            c03: translation.x, c13: translation.y, c23: translation.z, c33: 1.0,
-        }
-    }
-
-    /// Creates a non-uniform scaling matrix from a `Vector3`.
-    pub const fn from_nonuniform_scale(scale: &Vector3) -> Self {
-        Self {
-            c00: scale.x, c10: 0.0,     c20: 0.0,     c30: 0.0,
-            c01: 0.0,     c11: scale.y, c21: 0.0,     c31: 0.0,
-            c02: 0.0,     c12: 0.0,     c22: scale.z, c32: 0.0,
-            c03: 0.0,     c13: 0.0,     c23: 0.0,     c33: 1.0,
-        }
-    }
-    
-    /// Creates a uniform scaling matrix from a `Scalar`.
-    pub const fn from_scale(scale: Scalar) -> Self {
-        Self {
-            c00: scale, c10: 0.0,   c20: 0.0,   c30: 0.0,
-            c01: 0.0,   c11: scale, c21: 0.0,   c31: 0.0,
-            c02: 0.0,   c12: 0.0,   c22: scale, c32: 0.0,
-            c03: 0.0,   c13: 0.0,   c23: 0.0,   c33: 1.0,
+use crate::quaternion::{Quaternion, DQuaternion};
+use super::{Matrix3x3, DMatrix3x3};
+
+macro_rules! define_matrix4x4 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $matrix3x3: ident, $quaternion: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub c00: $scalar, pub c10: $scalar, pub c20: $scalar, pub c30: $scalar,
+            pub c01: $scalar, pub c11: $scalar, pub c21: $scalar, pub c31: $scalar,
+            pub c02: $scalar, pub c12: $scalar, pub c22: $scalar, pub c32: $scalar,
+            pub c03: $scalar, pub c13: $scalar, pub c23: $scalar, pub c33: $scalar,
         }
-    }
-
-    /// Compiles a matrix of minors for this matrix.
-    pub fn matrix_of_minors(&self) -> Matrix4x4 {
-        let c00 = Matrix3x3 {
-            c00: self.c11, c10: self.c21, c20: self.c31,
-            c01: self.c12, c11: self.c22, c21: self.c32,
-            c02: self.c13, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c10 = Matrix3x3 {
-            c00: self.c01, c10: self.c21, c20: self.c31,
-            c01: self.c02, c11: self.c22, c21: self.c32,
-            c02: self.c03, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c20 = Matrix3x3 {
-            c00: self.c01, c10: self.c11, c20: self.c31,
-            c01: self.c02, c11: self.c12, c21: self.c32,
-            c02: self.c03, c12: self.c13, c22: self.c33,
-        }.determinant();
-        let c30  = Matrix3x3 {
-            c00: self.c01, c10: self.c11, c20: self.c21,
-            c01: self.c02, c11: self.c12, c21: self.c22,
-            c02: self.c03, c12: self.c13, c22: self.c23,
-        }.determinant();
-
-        let c01 = Matrix3x3 {
-            c00: self.c10, c10: self.c20, c20: self.c30,
-            c01: self.c12, c11: self.c22, c21: self.c32,
-            c02: self.c13, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c11 = Matrix3x3 {
-            c00: self.c00, c10: self.c20, c20: self.c30,
-            c01: self.c02, c11: self.c22, c21: self.c32,
-            c02: self.c03, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c21 = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c30,
-            c01: self.c02, c11: self.c12, c21: self.c32,
-            c02: self.c03, c12: self.c13, c22: self.c33,
-        }.determinant();
-        let c31  = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c20,
-            c01: self.c02, c11: self.c12, c21: self.c22,
-            c02: self.c03, c12: self.c13, c22: self.c23,
-        }.determinant();
-        
-        let c02 = Matrix3x3 {
-            c00: self.c10, c10: self.c20, c20: self.c30,
-            c01: self.c11, c11: self.c21, c21: self.c31,
-            c02: self.c13, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c12 = Matrix3x3 {
-            c00: self.c00, c10: self.c20, c20: self.c30,
-            c01: self.c01, c11: self.c21, c21: self.c31,
-            c02: self.c03, c12: self.c23, c22: self.c33,
-        }.determinant();
-        let c22 = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c30,
-            c01: self.c01, c11: self.c11, c21: self.c31,
-            c02: self.c03, c12: self.c13, c22: self.c33,
-        }.determinant();
-        let c32  = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c20,
-            c01: self.c01, c11: self.c11, c21: self.c21,
-            c02: self.c03, c12: self.c13, c22: self.c23,
-        }.determinant();
-        
-        let c03 = Matrix3x3 {
-            c00: self.c10, c10: self.c20, c20: self.c30,
-            c01: self.c11, c11: self.c21, c21: self.c31,
-            c02: self.c12, c12: self.c22, c22: self.c32,
-        }.determinant();
-        let c13 = Matrix3x3 {
-            c00: self.c00, c10: self.c20, c20: self.c30,
-            c01: self.c01, c11: self.c21, c21: self.c31,
-            c02: self.c02, c12: self.c22, c22: self.c32,
-        }.determinant();
-        let c23 = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c30,
-            c01: self.c01, c11: self.c11, c21: self.c31,
-            c02: self.c02, c12: self.c12, c22: self.c32,
-        }.determinant();
-        let c33  = Matrix3x3 {
-            c00: self.c00, c10: self.c10, c20: self.c20,
-            c01: self.c01, c11: self.c11, c21: self.c21,
-            c02: self.c02, c12: self.c12, c22: self.c22,
-        }.determinant();
-
-        Matrix4x4 {
-            c00, c10, c20, c30,
-            c01, c11, c21, c31,
-            c02, c12, c22, c32,
-            c03, c13, c23, c33,
+        impl_add_self!($type, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+        impl_sub_self!($type, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+        impl_mul_scalar!($type; $scalar; c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+        impl_div_scalar!($type; $scalar; c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+        impl_negate_self!($type, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+        impl_approx!($type; $scalar; c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
+
+        impl $type {
+            pub const fn identity() -> Self {
+                Self {
+                    c00: 1.0, c10: 0.0, c20: 0.0, c30: 0.0,
+                    c01: 0.0, c11: 1.0, c21: 0.0, c31: 0.0,
+                    c02: 0.0, c12: 0.0, c22: 1.0, c32: 0.0,
+                    c03: 0.0, c13: 0.0, c23: 0.0, c33: 1.0,
+                }
+            }
+
+            /// Constructs a new perspective projection. As a reminder, this will create a left-handed perspective matrix.
+            /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
+            ///
+            /// The parameters follow convention as far as I am aware, but to clarify:
+            /// - `fovy` corresponds to the width of the field of view visible (or how far out you can hold your arms).
+            /// - `aspect ratio` is the ratio of width to height for the screen (so aspect_ratio = screen_width / screen_height).
+            /// - `near_plane` defines the start of the clipping volume for the camera along the z (the minimum distance at which objects can be rendered).
+            /// - `far_plane` defines the end of the clipping volume for the camera along the z (the maximum distance at which objects can be rendered).
+            pub fn new_perspective_projection(fov: Degrees, aspect_ratio: $scalar, near_plane: $scalar, far_plane: $scalar) -> Self {
+                assert!(aspect_ratio != 0.0);
+                assert!(near_plane - far_plane != 0.0);
+                let mut result = Self::default();
+                let x_scale = 2.0 / <$scalar as AngleScalar>::radians(fov).tan();
+                result.c00 = x_scale;
+                result.c11 = x_scale / aspect_ratio;
+                result.c22 = far_plane / (far_plane - near_plane);
+                result.c32 = 1.0;
+                result.c33 = near_plane * far_plane / (near_plane - far_plane);
+                result
+            }
+
+            /// Constructs a new orthographic projection. As a reminder, this will create a left-handed orthographic matrix.
+            /// If you require a right-handed coordinate system, you'll have to convert to it with with a reflection matrix.
+            ///
+            /// I opted for simple parameters for this method, so you can create any size or shape orthographic bounding volume you desire.
+            /// However, my advice would be for your code to define an `orthographic_size` variable somehow. You can then convert to top/bottom/left/right as follows:
+            /// ```
+            /// struct OrthoBounds {
+            ///     top: f32,
+            ///     bottom: f32,
+            ///     left: f32,
+            ///     right: f32,
+            /// }
+            ///
+            /// fn get_ortho_bounds(orthographic_size: f32, aspect_ratio: f32) -> OrthoBounds {
+            ///     OrthoBounds {
+            ///         top: 0.5 * orthographic_size,
+            ///         bottom: -0.5 * orthographic_size,
+            ///         left: -0.5 * orthographic_size * aspect_ratio,
+            ///         right: 0.5 * orthographic_size * aspect_ratio,
+            ///     }
+            /// }
+            /// ```
+            /// If you choose not to use the above method, bear in mind that `top - bottom` and `right - left` should **never** equal 0. This will cause a panic.
+            pub fn new_orthographic_projection(top: $scalar, bottom: $scalar, left: $scalar, right: $scalar, near_plane: $scalar, far_plane: $scalar) -> Self {
+                assert!(top - bottom != 0.0);
+                assert!(left - right != 0.0);
+                assert!(near_plane - far_plane != 0.0);
+                Self {
+                    c00: 2.0 / (right - left),
+                    c11: 2.0 / (top - bottom),
+                    c22: 1.0 / (far_plane - near_plane),
+                    c23: -near_plane / (far_plane - near_plane),
+                    c33: 1.0,
+                    ..Self::default()
+                }
+            }
+
+            #[doc = concat!("Creates a translation matrix from a `", stringify!($vector3), "`.")]
+            pub const fn from_translation(translation: &$vector3) -> Self {
+                Self {
+                    c00: 1.0, c10: 0.0, c20: 0.0, c30: 0.0,
+                    c01: 0.0, c11: 1.0, c21: 0.0, c31: 0.0,
+                    c02: 0.0, c12: 0.0, c22: 1.0, c32: 0.0,
+                    c03: translation.x, c13: translation.y, c23: translation.z, c33: 1.0,
+                }
+            }
+
+            #[doc = concat!("Creates a non-uniform scaling matrix from a `", stringify!($vector3), "`.")]
+            pub const fn from_nonuniform_scale(scale: &$vector3) -> Self {
+                Self {
+                    c00: scale.x, c10: 0.0,     c20: 0.0,     c30: 0.0,
+                    c01: 0.0,     c11: scale.y, c21: 0.0,     c31: 0.0,
+                    c02: 0.0,     c12: 0.0,     c22: scale.z, c32: 0.0,
+                    c03: 0.0,     c13: 0.0,     c23: 0.0,     c33: 1.0,
+                }
+            }
+
+            /// Creates a uniform scaling matrix from a scalar.
+            pub const fn from_scale(scale: $scalar) -> Self {
+                Self {
+                    c00: scale, c10: 0.0,   c20: 0.0,   c30: 0.0,
+                    c01: 0.0,   c11: scale, c21: 0.0,   c31: 0.0,
+                    c02: 0.0,   c12: 0.0,   c22: scale, c32: 0.0,
+                    c03: 0.0,   c13: 0.0,   c23: 0.0,   c33: 1.0,
+                }
+            }
+
+            /// Creates a rotation of a given angle around a given axis, which is expected to be normalized.
+            ///
+            /// This is the same rotation as `Quaternion::from_axis_angle` creates.
+            pub fn from_axis_angle(axis: &$vector3, angle: Degrees) -> Self {
+                Self::from_affine_parts($matrix3x3::from_axis_angle(axis, angle), $vector3::default())
+            }
+
+            /// Creates a rotation of a given angle around the X axis.
+            pub fn from_rotation_x(angle: Degrees) -> Self {
+                Self::from_affine_parts($matrix3x3::from_rotation_x(angle), $vector3::default())
+            }
+
+            /// Creates a rotation of a given angle around the Y axis.
+            pub fn from_rotation_y(angle: Degrees) -> Self {
+                Self::from_affine_parts($matrix3x3::from_rotation_y(angle), $vector3::default())
+            }
+
+            /// Creates a rotation of a given angle around the Z axis.
+            pub fn from_rotation_z(angle: Degrees) -> Self {
+                Self::from_affine_parts($matrix3x3::from_rotation_z(angle), $vector3::default())
+            }
+
+            /// Creates a matrix that maps the X, Y and Z axes onto the given right, up and forward directions.
+            ///
+            /// The directions are used as they are, so they should be normalized and perpendicular for a pure rotation.
+            pub fn from_basis(right: &$vector3, up: &$vector3, forward: &$vector3) -> Self {
+                Self::from_affine_parts($matrix3x3::from_basis(right, up, forward), $vector3::default())
+            }
+
+            /// Creates a reflection through a plane, such as a mirror or a water surface for planar reflections.
+            ///
+            /// The plane contains every point `p` where `p.dot(normal) == distance` once the normal is normalized.
+            pub fn from_reflection(normal: &$vector3, distance: $scalar) -> Self {
+                let mut n = *normal;
+                n.normalize();
+                Self::from_affine_parts($matrix3x3::from_reflection(&n), n * (2.0 * distance))
+            }
+
+            /// Creates a shear. Each parameter is the amount that the first axis is offset by, per unit along the second.
+            /// For example, `x_by_y` is how far a point moves along X for each unit of Y.
+            pub fn from_shear(x_by_y: $scalar, x_by_z: $scalar, y_by_x: $scalar, y_by_z: $scalar, z_by_x: $scalar, z_by_y: $scalar) -> Self {
+                Self::from_affine_parts($matrix3x3::from_shear(x_by_y, x_by_z, y_by_x, y_by_z, z_by_x, z_by_y), $vector3::default())
+            }
+
+            /// Compiles a matrix of minors for this matrix.
+            pub fn matrix_of_minors(&self) -> $type {
+                let c00 = $matrix3x3 {
+                    c00: self.c11, c10: self.c21, c20: self.c31,
+                    c01: self.c12, c11: self.c22, c21: self.c32,
+                    c02: self.c13, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c10 = $matrix3x3 {
+                    c00: self.c01, c10: self.c21, c20: self.c31,
+                    c01: self.c02, c11: self.c22, c21: self.c32,
+                    c02: self.c03, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c20 = $matrix3x3 {
+                    c00: self.c01, c10: self.c11, c20: self.c31,
+                    c01: self.c02, c11: self.c12, c21: self.c32,
+                    c02: self.c03, c12: self.c13, c22: self.c33,
+                }.determinant();
+                let c30  = $matrix3x3 {
+                    c00: self.c01, c10: self.c11, c20: self.c21,
+                    c01: self.c02, c11: self.c12, c21: self.c22,
+                    c02: self.c03, c12: self.c13, c22: self.c23,
+                }.determinant();
+
+                let c01 = $matrix3x3 {
+                    c00: self.c10, c10: self.c20, c20: self.c30,
+                    c01: self.c12, c11: self.c22, c21: self.c32,
+                    c02: self.c13, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c11 = $matrix3x3 {
+                    c00: self.c00, c10: self.c20, c20: self.c30,
+                    c01: self.c02, c11: self.c22, c21: self.c32,
+                    c02: self.c03, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c21 = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c30,
+                    c01: self.c02, c11: self.c12, c21: self.c32,
+                    c02: self.c03, c12: self.c13, c22: self.c33,
+                }.determinant();
+                let c31  = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c20,
+                    c01: self.c02, c11: self.c12, c21: self.c22,
+                    c02: self.c03, c12: self.c13, c22: self.c23,
+                }.determinant();
+
+                let c02 = $matrix3x3 {
+                    c00: self.c10, c10: self.c20, c20: self.c30,
+                    c01: self.c11, c11: self.c21, c21: self.c31,
+                    c02: self.c13, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c12 = $matrix3x3 {
+                    c00: self.c00, c10: self.c20, c20: self.c30,
+                    c01: self.c01, c11: self.c21, c21: self.c31,
+                    c02: self.c03, c12: self.c23, c22: self.c33,
+                }.determinant();
+                let c22 = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c30,
+                    c01: self.c01, c11: self.c11, c21: self.c31,
+                    c02: self.c03, c12: self.c13, c22: self.c33,
+                }.determinant();
+                let c32  = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c20,
+                    c01: self.c01, c11: self.c11, c21: self.c21,
+                    c02: self.c03, c12: self.c13, c22: self.c23,
+                }.determinant();
+
+                let c03 = $matrix3x3 {
+                    c00: self.c10, c10: self.c20, c20: self.c30,
+                    c01: self.c11, c11: self.c21, c21: self.c31,
+                    c02: self.c12, c12: self.c22, c22: self.c32,
+                }.determinant();
+                let c13 = $matrix3x3 {
+                    c00: self.c00, c10: self.c20, c20: self.c30,
+                    c01: self.c01, c11: self.c21, c21: self.c31,
+                    c02: self.c02, c12: self.c22, c22: self.c32,
+                }.determinant();
+                let c23 = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c30,
+                    c01: self.c01, c11: self.c11, c21: self.c31,
+                    c02: self.c02, c12: self.c12, c22: self.c32,
+                }.determinant();
+                let c33  = $matrix3x3 {
+                    c00: self.c00, c10: self.c10, c20: self.c20,
+                    c01: self.c01, c11: self.c11, c21: self.c21,
+                    c02: self.c02, c12: self.c12, c22: self.c22,
+                }.determinant();
+
+                $type {
+                    c00, c10, c20, c30,
+                    c01, c11, c21, c31,
+                    c02, c12, c22, c32,
+                    c03, c13, c23, c33,
+                }
+            }
+
+            /// Compiles a matrix of cofactors for this matrix.
+            pub fn matrix_of_cofactors(&self) -> $type {
+                $type {
+                    c00:  self.c00, c10: -self.c10, c20:  self.c20, c30: -self.c30,
+                    c01: -self.c01, c11:  self.c11, c21: -self.c21, c31:  self.c31,
+                    c02:  self.c02, c12: -self.c12, c22:  self.c22, c32: -self.c32,
+                    c03: -self.c03, c13:  self.c13, c23: -self.c23, c33:  self.c33,
+                }
+            }
+
+            /// Returns a new matrix with the elements transposed.
+            pub fn transpose(&self) -> $type {
+                $type {
+                    c00: self.c00, c10: self.c01, c20: self.c02, c30: self.c03,
+                    c01: self.c10, c11: self.c11, c21: self.c12, c31: self.c13,
+                    c02: self.c20, c12: self.c21, c22: self.c22, c32: self.c23,
+                    c03: self.c30, c13: self.c31, c23: self.c32, c33: self.c33,
+                }
+            }
+
+            /// Calculates the determinant for this matrix.
+            pub fn determinant(&self) -> $scalar {
+                let minors = self.matrix_of_minors();
+                let cofactors = minors.matrix_of_cofactors();
+                self.c00 * cofactors.c00 + self.c10 * cofactors.c10 + self.c20 * cofactors.c20 + self.c30 * cofactors.c30
+            }
+
+            /// Calculates the Inverse matrix for this matrix.
+            /// Affine matrices (see `is_affine`) are inverted with the faster and more precise `inverse_affine`.
+            /// The determinant is not checked, so a singular matrix results in inf/NaN elements. Use `try_inverse` if this is a possibility.
+            pub fn inverse(&self) -> $type {
+                if self.is_affine() {
+                    return self.inverse_affine();
+                }
+                let minors = self.matrix_of_minors();
+                let cofactors = minors.matrix_of_cofactors();
+                let adjugate = cofactors.transpose();
+                let determinant = self.determinant();
+                adjugate * (1.0 / determinant)
+            }
+
+            /// Calculates the Inverse matrix for this matrix, unless it is singular.
+            ///
+            /// Unlike `inverse`, this returns `Error::SingularMatrix` rather than a matrix of inf/NaN
+            /// when the absolute determinant is less than or equal to `tolerance`.
+            pub fn try_inverse(&self, tolerance: $scalar) -> Result<$type, Error> {
+                if self.is_affine() {
+                    let inverse = $matrix3x3::from(*self).try_inverse(tolerance)?;
+                    return Ok(Self::from_affine_parts(inverse, -(self.translation() * inverse)));
+                }
+                let determinant = self.determinant();
+                if determinant.is_nan() || determinant.abs() <= tolerance {
+                    return Err(Error::SingularMatrix);
+                }
+                let adjugate = self.matrix_of_minors().matrix_of_cofactors().transpose();
+                Ok(adjugate * (1.0 / determinant))
+            }
+
+            /// Calculates the Inverse matrix for this matrix, or `None` if it is singular. See `try_inverse`.
+            pub fn checked_inverse(&self, tolerance: $scalar) -> Option<$type> {
+                self.try_inverse(tolerance).ok()
+            }
+
+            /// Makes the rows of the upper 3x3 of this matrix perpendicular and of unit length, leaving the other
+            /// elements as they are. See `Matrix3x3::orthonormalize`.
+            pub fn orthonormalize(&self) -> $type {
+                self.with_upper3x3($matrix3x3::from(*self).orthonormalize())
+            }
+
+            /// Returns true if the upper 3x3 of this matrix is orthonormal to within `epsilon`. See `Matrix3x3::is_orthonormal`.
+            pub fn is_orthonormal(&self, epsilon: $scalar) -> bool {
+                $matrix3x3::from(*self).is_orthonormal(epsilon)
+            }
+
+            /// Returns true if the upper 3x3 of this matrix is a rotation to within `epsilon`. See `Matrix3x3::is_rotation`.
+            pub fn is_rotation(&self, epsilon: $scalar) -> bool {
+                $matrix3x3::from(*self).is_rotation(epsilon)
+            }
+
+            /// Replaces the upper 3x3 of this matrix with the rotation closest to it, leaving the other elements,
+            /// such as the translation, as they are. See `Matrix3x3::nearest_rotation`.
+            pub fn nearest_rotation(&self) -> $type {
+                self.with_upper3x3($matrix3x3::from(*self).nearest_rotation())
+            }
+
+            /// Returns true if this matrix is affine, meaning that its last column is exactly (0, 0, 0, 1).
+            /// Any combination of translation, rotation, scale and shear is affine, while a projection is not.
+            pub fn is_affine(&self) -> bool {
+                self.c30 == 0.0 && self.c31 == 0.0 && self.c32 == 0.0 && self.c33 == 1.0
+            }
+
+            /// Calculates the Inverse matrix for an affine matrix, by inverting the upper 3x3 and the translation separately.
+            /// This is faster and more precise than the general `inverse`, but gives a wrong result if the matrix is not affine.
+            pub fn inverse_affine(&self) -> $type {
+                let inverse = $matrix3x3::from(*self).inverse();
+                Self::from_affine_parts(inverse, -(self.translation() * inverse))
+            }
+
+            /// Calculates the Inverse matrix for a rigid transformation, made only of a rotation and a translation.
+            /// The rotation is inverted by transposing it, which makes this the cheapest way to invert a camera's view matrix,
+            /// but gives a wrong result if the matrix contains any scale, shear or projection.
+            pub fn inverse_rigid(&self) -> $type {
+                let inverse = $matrix3x3::from(*self).transpose();
+                Self::from_affine_parts(inverse, -(self.translation() * inverse))
+            }
+
+            /// Transforms a position by this matrix, treating it as a point with a w of 1.
+            /// Translation is applied, and the result is divided by w so that projection matrices work as expected.
+            /// This is the same as multiplying a `Point3` by the matrix.
+            pub fn transform_point3(&self, point: &$vector3) -> $vector3 {
+                let w = point.x * self.c30 + point.y * self.c31 + point.z * self.c32 + self.c33;
+                $vector3 {
+                    x: (point.x * self.c00 + point.y * self.c01 + point.z * self.c02 + self.c03) / w,
+                    y: (point.x * self.c10 + point.y * self.c11 + point.z * self.c12 + self.c13) / w,
+                    z: (point.x * self.c20 + point.y * self.c21 + point.z * self.c22 + self.c23) / w,
+                }
+            }
+
+            /// Transforms a direction by this matrix, treating it as a vector with a w of 0.
+            /// Translation is not applied, so this is suitable for directions, velocities and offsets.
+            /// Do not use this for surface normals, see `transform_normal` instead. This is the same as `vector * matrix`.
+            pub fn transform_vector3(&self, vector: &$vector3) -> $vector3 {
+                *vector * $matrix3x3::from(*self)
+            }
+
+            /// Transforms a surface normal by this matrix, using the `normal_matrix`. This keeps normals
+            /// perpendicular to their surface under non-uniform scale. The result is not normalized.
+            /// The upper 3x3 is not checked, so a singular matrix, such as a scale of zero, results in inf/NaN elements.
+            /// Use `try_transform_normal` if this is a possibility.
+            pub fn transform_normal(&self, normal: &$vector3) -> $vector3 {
+                *normal * self.normal_matrix()
+            }
+
+            /// Transforms a surface normal by this matrix, unless its upper 3x3 is singular. See `transform_normal`.
+            ///
+            /// Unlike `transform_normal`, this returns `Error::SingularMatrix` rather than inf/NaN elements
+            /// when the absolute determinant of the upper 3x3 is less than or equal to `tolerance`.
+            pub fn try_transform_normal(&self, normal: &$vector3, tolerance: $scalar) -> Result<$vector3, Error> {
+                Ok(*normal * self.try_normal_matrix(tolerance)?)
+            }
+
+            /// Returns the matrix used to transform surface normals: the inverse-transpose of the upper 3x3 of this matrix.
+            /// For transformations made only of rotations and uniform scale, this is proportional to the upper 3x3 itself.
+            /// The determinant is not checked, so a singular matrix results in inf/NaN elements. Use `try_normal_matrix`
+            /// if this is a possibility.
+            pub fn normal_matrix(&self) -> $matrix3x3 {
+                $matrix3x3::from(*self).inverse().transpose()
+            }
+
+            /// Returns the matrix used to transform surface normals, unless the upper 3x3 of this matrix is singular.
+            ///
+            /// Unlike `normal_matrix`, this returns `Error::SingularMatrix` rather than a matrix of inf/NaN
+            /// when the absolute determinant of the upper 3x3 is less than or equal to `tolerance`.
+            pub fn try_normal_matrix(&self, tolerance: $scalar) -> Result<$matrix3x3, Error> {
+                Ok($matrix3x3::from(*self).try_inverse(tolerance)?.transpose())
+            }
+
+            fn translation(&self) -> $vector3 {
+                $vector3::new(self.c03, self.c13, self.c23)
+            }
+
+            fn with_upper3x3(&self, m: $matrix3x3) -> Self {
+                Self {
+                    c00: m.c00, c10: m.c10, c20: m.c20,
+                    c01: m.c01, c11: m.c11, c21: m.c21,
+                    c02: m.c02, c12: m.c12, c22: m.c22,
+                    ..*self
+                }
+            }
+
+            fn from_affine_parts(m: $matrix3x3, translation: $vector3) -> Self {
+                Self {
+                    c00: m.c00,         c10: m.c10,         c20: m.c20,         c30: 0.0,
+                    c01: m.c01,         c11: m.c11,         c21: m.c21,         c31: 0.0,
+                    c02: m.c02,         c12: m.c12,         c22: m.c22,         c32: 0.0,
+                    c03: translation.x, c13: translation.y, c23: translation.z, c33: 1.0,
+                }
+            }
         }
-    }
-    
-    /// Compiles a matrix of cofactors for this matrix.
-    pub fn matrix_of_cofactors(&self) -> Matrix4x4 {
-        Matrix4x4 {
-            c00:  self.c00, c10: -self.c10, c20:  self.c20, c30: -self.c30,
-            c01: -self.c01, c11:  self.c11, c21: -self.c21, c31:  self.c31,
-            c02:  self.c02, c12: -self.c12, c22:  self.c22, c32: -self.c32,
-            c03: -self.c03, c13:  self.c13, c23: -self.c23, c33:  self.c33,
+
+        impl std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02 + self.c30 * rhs.c03,
+                    c10: self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12 + self.c30 * rhs.c13,
+                    c20: self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22 + self.c30 * rhs.c23,
+                    c30: self.c00 * rhs.c30 + self.c10 * rhs.c31 + self.c20 * rhs.c32 + self.c30 * rhs.c33,
+
+                    c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02 + self.c31 * rhs.c03,
+                    c11: self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12 + self.c31 * rhs.c13,
+                    c21: self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22 + self.c31 * rhs.c23,
+                    c31: self.c01 * rhs.c30 + self.c11 * rhs.c31 + self.c21 * rhs.c32 + self.c31 * rhs.c33,
+
+                    c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02 + self.c32 * rhs.c03,
+                    c12: self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12 + self.c32 * rhs.c13,
+                    c22: self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22 + self.c32 * rhs.c23,
+                    c32: self.c02 * rhs.c30 + self.c12 * rhs.c31 + self.c22 * rhs.c32 + self.c32 * rhs.c33,
+
+                    c03: self.c03 * rhs.c00 + self.c13 * rhs.c01 + self.c23 * rhs.c02 + self.c33 * rhs.c03,
+                    c13: self.c03 * rhs.c10 + self.c13 * rhs.c11 + self.c23 * rhs.c12 + self.c33 * rhs.c13,
+                    c23: self.c03 * rhs.c20 + self.c13 * rhs.c21 + self.c23 * rhs.c22 + self.c33 * rhs.c23,
+                    c33: self.c03 * rhs.c30 + self.c13 * rhs.c31 + self.c23 * rhs.c32 + self.c33 * rhs.c33,
+                }
+            }
         }
-    }
-
-    /// Returns a new matrix with the elements transposed.
-    pub fn transpose(&self) -> Matrix4x4 {
-        Matrix4x4 {
-            c00: self.c00, c10: self.c01, c20: self.c02, c30: self.c03,
-            c01: self.c10, c11: self.c11, c21: self.c12, c31: self.c13,
-            c02: self.c20, c12: self.c21, c22: self.c22, c32: self.c23,
-            c03: self.c30, c13: self.c31, c23: self.c32, c33: self.c33,
+
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02 + self.c30 * rhs.c03;
+                let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12 + self.c30 * rhs.c13;
+                let c20 = self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22 + self.c30 * rhs.c23;
+                let c30 = self.c00 * rhs.c30 + self.c10 * rhs.c31 + self.c20 * rhs.c32 + self.c30 * rhs.c33;
+
+                let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02 + self.c31 * rhs.c03;
+                let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12 + self.c31 * rhs.c13;
+                let c21 = self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22 + self.c31 * rhs.c23;
+                let c31 = self.c01 * rhs.c30 + self.c11 * rhs.c31 + self.c21 * rhs.c32 + self.c31 * rhs.c33;
+
+                let c02 = self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02 + self.c32 * rhs.c03;
+                let c12 = self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12 + self.c32 * rhs.c13;
+                let c22 = self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22 + self.c32 * rhs.c23;
+                let c32 = self.c02 * rhs.c30 + self.c12 * rhs.c31 + self.c22 * rhs.c32 + self.c32 * rhs.c33;
+
+                let c03 = self.c03 * rhs.c00 + self.c13 * rhs.c01 + self.c23 * rhs.c02 + self.c33 * rhs.c03;
+                let c13 = self.c03 * rhs.c10 + self.c13 * rhs.c11 + self.c23 * rhs.c12 + self.c33 * rhs.c13;
+                let c23 = self.c03 * rhs.c20 + self.c13 * rhs.c21 + self.c23 * rhs.c22 + self.c33 * rhs.c23;
+                let c33 = self.c03 * rhs.c30 + self.c13 * rhs.c31 + self.c23 * rhs.c32 + self.c33 * rhs.c33;
+
+                self.c00 = c00; self.c10 = c10; self.c20 = c20; self.c30 = c30;
+                self.c01 = c01; self.c11 = c11; self.c21 = c21; self.c31 = c31;
+                self.c02 = c02; self.c12 = c12; self.c22 = c22; self.c32 = c32;
+                self.c03 = c03; self.c13 = c13; self.c23 = c23; self.c33 = c33;
+            }
         }
-    }
-
-    /// Calculates the determinant for this matrix.
-    pub fn determinant(&self) -> f32 {
-        let minors = self.matrix_of_minors();
-        let cofactors = minors.matrix_of_cofactors();
-        self.c00 * cofactors.c00 + self.c10 * cofactors.c10 + self.c20 * cofactors.c20 + self.c30 * cofactors.c30
-    }
-
-    /// Calculates the Inverse matrix for this matrix.
-    pub fn inverse(&self) -> Matrix4x4 {
-        let minors = self.matrix_of_minors();
-        let cofactors = minors.matrix_of_cofactors();
-        let adjugate = cofactors.transpose();
-        let determinant = self.determinant();
-        adjugate * (1.0 / determinant)
-    }
-}
 
-impl std::ops::Mul for Matrix4x4 {
-    type Output = Matrix4x4;
-
-    fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
-        Matrix4x4 {
-            c00: self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02 + self.c30 * rhs.c03,
-            c10: self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12 + self.c30 * rhs.c13,
-            c20: self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22 + self.c30 * rhs.c23,
-            c30: self.c00 * rhs.c30 + self.c10 * rhs.c31 + self.c20 * rhs.c32 + self.c30 * rhs.c33,
-
-            c01: self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02 + self.c31 * rhs.c03,
-            c11: self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12 + self.c31 * rhs.c13,
-            c21: self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22 + self.c31 * rhs.c23,
-            c31: self.c01 * rhs.c30 + self.c11 * rhs.c31 + self.c21 * rhs.c32 + self.c31 * rhs.c33,
-
-            c02: self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02 + self.c32 * rhs.c03,
-            c12: self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12 + self.c32 * rhs.c13,
-            c22: self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22 + self.c32 * rhs.c23,
-            c32: self.c02 * rhs.c30 + self.c12 * rhs.c31 + self.c22 * rhs.c32 + self.c32 * rhs.c33,
-
-            c03: self.c03 * rhs.c00 + self.c13 * rhs.c01 + self.c23 * rhs.c02 + self.c33 * rhs.c03,
-            c13: self.c03 * rhs.c10 + self.c13 * rhs.c11 + self.c23 * rhs.c12 + self.c33 * rhs.c13,
-            c23: self.c03 * rhs.c20 + self.c13 * rhs.c21 + self.c23 * rhs.c22 + self.c33 * rhs.c23,
-            c33: self.c03 * rhs.c30 + self.c13 * rhs.c31 + self.c23 * rhs.c32 + self.c33 * rhs.c33,
+        impl From<$type> for $matrix3x3 {
+            fn from(m: $type) -> Self {
+                $matrix3x3 {
+                    c00: m.c00, c10: m.c10, c20: m.c20,
+                    c01: m.c01, c11: m.c11, c21: m.c21,
+                    c02: m.c02, c12: m.c12, c22: m.c22,
+                }
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign for Matrix4x4 {
-    fn mul_assign(&mut self, rhs: Matrix4x4) {
-        let c00 = self.c00 * rhs.c00 + self.c10 * rhs.c01 + self.c20 * rhs.c02 + self.c30 * rhs.c03;
-        let c10 = self.c00 * rhs.c10 + self.c10 * rhs.c11 + self.c20 * rhs.c12 + self.c30 * rhs.c13;
-        let c20 = self.c00 * rhs.c20 + self.c10 * rhs.c21 + self.c20 * rhs.c22 + self.c30 * rhs.c23;
-        let c30 = self.c00 * rhs.c30 + self.c10 * rhs.c31 + self.c20 * rhs.c32 + self.c30 * rhs.c33;
-
-        let c01 = self.c01 * rhs.c00 + self.c11 * rhs.c01 + self.c21 * rhs.c02 + self.c31 * rhs.c03;
-        let c11 = self.c01 * rhs.c10 + self.c11 * rhs.c11 + self.c21 * rhs.c12 + self.c31 * rhs.c13;
-        let c21 = self.c01 * rhs.c20 + self.c11 * rhs.c21 + self.c21 * rhs.c22 + self.c31 * rhs.c23;
-        let c31 = self.c01 * rhs.c30 + self.c11 * rhs.c31 + self.c21 * rhs.c32 + self.c31 * rhs.c33;
-
-        let c02 = self.c02 * rhs.c00 + self.c12 * rhs.c01 + self.c22 * rhs.c02 + self.c32 * rhs.c03;
-        let c12 = self.c02 * rhs.c10 + self.c12 * rhs.c11 + self.c22 * rhs.c12 + self.c32 * rhs.c13;
-        let c22 = self.c02 * rhs.c20 + self.c12 * rhs.c21 + self.c22 * rhs.c22 + self.c32 * rhs.c23;
-        let c32 = self.c02 * rhs.c30 + self.c12 * rhs.c31 + self.c22 * rhs.c32 + self.c32 * rhs.c33;
-
-        let c03 = self.c03 * rhs.c00 + self.c13 * rhs.c01 + self.c23 * rhs.c02 + self.c33 * rhs.c03;
-        let c13 = self.c03 * rhs.c10 + self.c13 * rhs.c11 + self.c23 * rhs.c12 + self.c33 * rhs.c13;
-        let c23 = self.c03 * rhs.c20 + self.c13 * rhs.c21 + self.c23 * rhs.c22 + self.c33 * rhs.c23;
-        let c33 = self.c03 * rhs.c30 + self.c13 * rhs.c31 + self.c23 * rhs.c32 + self.c33 * rhs.c33;
-
-        self.c00 = c00; self.c10 = c10; self.c20 = c20; self.c30 = c30;
-        self.c01 = c01; self.c11 = c11; self.c21 = c21; self.c31 = c31;
-        self.c02 = c02; self.c12 = c12; self.c22 = c22; self.c32 = c32;
-        self.c03 = c03; self.c13 = c13; self.c23 = c23; self.c33 = c33;
-    }
-}
+        impl std::ops::Mul<$quaternion> for $type {
+            type Output = $type;
 
-impl std::ops::Mul<Quaternion> for Matrix4x4 {
-    type Output = Matrix4x4;
+            fn mul(self, rhs: $quaternion) -> Self::Output {
+                self * $type::from(rhs)
+            }
+        }
 
-    fn mul(self, rhs: Quaternion) -> Self::Output {
-        self * Matrix4x4::from(rhs)
-    }
-}
+        impl std::ops::MulAssign<$quaternion> for $type {
+            fn mul_assign(&mut self, rhs: $quaternion) {
+                *self *= $type::from(rhs);
+            }
+        }
 
-impl std::ops::MulAssign<Quaternion> for Matrix4x4 {
-    fn mul_assign(&mut self, rhs: Quaternion) {
-        *self *= Matrix4x4::from(rhs);
-    }
-}
+        impl From<$quaternion> for $type {
+            fn from(rotation: $quaternion) -> Self {
+                let x = rotation.x * 2.0;
+                let y = rotation.y * 2.0;
+                let z = rotation.z * 2.0;
+                let xx = rotation.x * x;
+                let yy = rotation.y * y;
+                let zz = rotation.z * z;
+                let xy = rotation.x * y;
+                let xz = rotation.x * z;
+                let yz = rotation.y * z;
+                let wx = rotation.w * x;
+                let wy = rotation.w * y;
+                let wz = rotation.w * z;
 
-impl From<Quaternion> for Matrix4x4 {
-    fn from(rotation: Quaternion) -> Self {
-        let x = rotation.x * 2.0;
-        let y = rotation.y * 2.0;
-        let z = rotation.z * 2.0;
-        let xx = rotation.x * x;
-        let yy = rotation.y * y;
-        let zz = rotation.z * z;
-        let xy = rotation.x * y;
-        let xz = rotation.x * z;
-        let yz = rotation.y * z;
-        let wx = rotation.w * x;
-        let wy = rotation.w * y;
-        let wz = rotation.w * z;
-
-        Self {
-            c00: 1.0 - (yy + zz), c10: xy - wz,         c20: xz + wy,         c30: 0.0,
-            c01: xy + wz,         c11: 1.0 - (xx + zz), c21: yz - wx,         c31: 0.0,
-            c02: xz - wy,         c12: yz + wx,         c22: 1.0 - (xx + yy), c32: 0.0,
-            c03: 0.0,             c13: 0.0,             c23: 0.0,             c33: 1.0,
+                Self {
+                    c00: 1.0 - (yy + zz), c10: xy - wz,         c20: xz + wy,         c30: 0.0,
+                    c01: xy + wz,         c11: 1.0 - (xx + zz), c21: yz - wx,         c31: 0.0,
+                    c02: xz - wy,         c12: yz + wx,         c22: 1.0 - (xx + yy), c32: 0.0,
+                    c03: 0.0,             c13: 0.0,             c23: 0.0,             c33: 1.0,
+                }
+            }
         }
-    }
+    };
 }
+
+define_matrix4x4!(
+    /// A 4 x 4 Matrix.
+    Matrix4x4, Scalar, Vector3, Matrix3x3, Quaternion
+);
+define_matrix4x4!(
+    /// A 4 x 4 Matrix, with double precision.
+    DMatrix4x4, f64, DVector3, DMatrix3x3, DQuaternion
+);
+
+impl_precision_conversion!(Matrix4x4, DMatrix4x4, c00, c10, c20, c30, c01, c11, c21, c31, c02, c12, c22, c32, c03, c13, c23, c33);
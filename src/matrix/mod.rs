@@ -1,4 +1,9 @@
-
+mod affine2d;
+pub use self::affine2d::*;
+mod decomposition;
+pub use self::decomposition::*;
+mod generic;
+pub use self::generic::*;
 mod matrix1x3;
 pub use self::matrix1x3::*;
 mod matrix2x2;
@@ -7,3 +12,5 @@ mod matrix3x3;
 pub use self::matrix3x3::*;
 mod matrix4x4;
 pub use self::matrix4x4::*;
+mod spectral;
+pub use self::spectral::*;
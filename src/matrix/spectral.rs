@@ -0,0 +1,200 @@
+use super::{Matrix, Matrix3x3, DMatrix, DMatrix3x3};
+use crate::vector::{Vector3, DVector3};
+
+macro_rules! define_spectral3x3 {
+    ($matrix3x3: ident, $generic: ident, $vector3: ident, $eigen: ident, $svd: ident, $scalar: ty) => {
+        #[doc = concat!("The eigenvalues and eigenvectors of a symmetric `", stringify!($matrix3x3), "`, from `", stringify!($matrix3x3), "::symmetric_eigen`.")]
+        ///
+        /// The eigenvalues are sorted from largest to smallest, and `eigenvectors[i] * matrix == eigenvectors[i] * eigenvalues[i]`.
+        /// The eigenvectors are normalized and form a right-handed basis, so `from_basis` turns them into a rotation.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $eigen {
+            pub eigenvalues: [$scalar; 3],
+            pub eigenvectors: [$vector3; 3],
+        }
+
+        #[doc = concat!("The singular value decomposition of a `", stringify!($matrix3x3), "`, from `", stringify!($matrix3x3), "::svd`.")]
+        ///
+        /// The matrix equals `u * Σ * v.transpose()`, where `Σ` is the diagonal matrix of `singular_values`,
+        /// and `u` and `v` are orthogonal. The singular values are sorted from largest to smallest, and are never negative.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $svd {
+            pub u: $matrix3x3,
+            pub singular_values: [$scalar; 3],
+            pub v: $matrix3x3,
+        }
+
+        impl $matrix3x3 {
+            /// Calculates the eigenvalues and eigenvectors of this matrix with the cyclic Jacobi method.
+            ///
+            /// The matrix is expected to be symmetric, such as an inertia tensor or a covariance matrix.
+            /// Only the symmetric part, `(self + self.transpose()) * 0.5`, is used.
+            pub fn symmetric_eigen(&self) -> $eigen {
+                let symmetric = $generic::from((*self + self.transpose()) * 0.5);
+                let (eigenvalues, v) = jacobi_eigen(symmetric);
+                let eigenvectors = [0, 1, 2].map(|j| $vector3::new(v.rows[0][j], v.rows[1][j], v.rows[2][j]));
+                $eigen { eigenvalues, eigenvectors }
+            }
+
+            /// Calculates the singular value decomposition of this matrix.
+            ///
+            /// This diagonalizes `self.transpose() * self` to find `v`, then uses a QR decomposition to find `u`,
+            /// which stays accurate when the matrix is singular or nearly so.
+            pub fn svd(&self) -> $svd {
+                let (u, singular_values, v) = signed_svd($generic::from(*self));
+                let mut u = u;
+                let mut singular_values = singular_values;
+                if singular_values[2] < 0.0 {
+                    singular_values[2] = -singular_values[2];
+                    for row in u.rows.iter_mut() {
+                        row[2] = -row[2];
+                    }
+                }
+                $svd { u: u.into(), singular_values, v: v.into() }
+            }
+
+            /// Splits this matrix into a rotation and a symmetric stretch, returned as `(rotation, stretch)`,
+            /// such that `self == stretch * rotation`. In reading order the stretch is applied first, as with
+            /// `scale * rotation`.
+            ///
+            /// The rotation is always a proper rotation. If this matrix contains a reflection, the stretch keeps it
+            /// as a negative scale along one of its axes.
+            pub fn polar_decomposition(&self) -> ($matrix3x3, $matrix3x3) {
+                let (u, singular_values, v) = signed_svd($generic::from(*self));
+                let rotation = u * v.transpose();
+                let mut sigma = $generic::<3, 3>::zero();
+                for (i, value) in singular_values.iter().enumerate() {
+                    sigma.rows[i][i] = *value;
+                }
+                let stretch = u * sigma * u.transpose();
+                (rotation.into(), stretch.into())
+            }
+
+            /// Returns the rotation closest to this matrix, which is the rotation of `polar_decomposition`.
+            ///
+            /// Use this to clean up a rotation matrix that has drifted after many multiplications.
+            pub fn nearest_rotation(&self) -> $matrix3x3 {
+                self.polar_decomposition().0
+            }
+        }
+
+        impl<const N: usize> $generic<N, N> {
+            /// Calculates the eigenvalues and eigenvectors of this matrix with the cyclic Jacobi method, returned as
+            /// `(eigenvalues, eigenvectors)`.
+            ///
+            /// The matrix is expected to be symmetric, and only its upper triangle is used. The eigenvalues are sorted
+            /// from largest to smallest, and column `i` of `eigenvectors` is the normalized eigenvector for `eigenvalues[i]`.
+            pub fn symmetric_eigen(&self) -> ([$scalar; N], $generic<N, N>) {
+                let mut a = *self;
+                for i in 0..N {
+                    for j in 0..i {
+                        a.rows[i][j] = a.rows[j][i];
+                    }
+                }
+                let mut v = $generic::<N, N>::identity();
+                let scale = a.rows.iter().flat_map(|row| row.iter()).map(|element| element * element).sum::<$scalar>();
+                for _ in 0..32 {
+                    let off_diagonal = (0..N).flat_map(|p| (p + 1..N).map(move |q| (p, q)))
+                        .map(|(p, q)| a.rows[p][q] * a.rows[p][q])
+                        .sum::<$scalar>();
+                    if off_diagonal <= scale * <$scalar>::EPSILON * <$scalar>::EPSILON {
+                        break;
+                    }
+                    for p in 0..N {
+                        for q in p + 1..N {
+                            if a.rows[p][q] == 0.0 {
+                                continue;
+                            }
+                            // Choose the smaller rotation angle that zeroes the element, for stability.
+                            let theta = (a.rows[q][q] - a.rows[p][p]) / (2.0 * a.rows[p][q]);
+                            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                            let c = 1.0 / (t * t + 1.0).sqrt();
+                            let s = t * c;
+                            for row in a.rows.iter_mut() {
+                                let (kp, kq) = (row[p], row[q]);
+                                row[p] = c * kp - s * kq;
+                                row[q] = s * kp + c * kq;
+                            }
+                            for k in 0..N {
+                                let (pk, qk) = (a.rows[p][k], a.rows[q][k]);
+                                a.rows[p][k] = c * pk - s * qk;
+                                a.rows[q][k] = s * pk + c * qk;
+                            }
+                            for row in v.rows.iter_mut() {
+                                let (kp, kq) = (row[p], row[q]);
+                                row[p] = c * kp - s * kq;
+                                row[q] = s * kp + c * kq;
+                            }
+                        }
+                    }
+                }
+
+                let mut order = [0; N];
+                for (i, o) in order.iter_mut().enumerate() {
+                    *o = i;
+                }
+                order.sort_by(|&i, &j| a.rows[j][j].partial_cmp(&a.rows[i][i]).unwrap_or(std::cmp::Ordering::Equal));
+                let eigenvalues = order.map(|i| a.rows[i][i]);
+                let mut vectors = $generic::<N, N>::zero();
+                for (column, &i) in order.iter().enumerate() {
+                    for (row, v_row) in vectors.rows.iter_mut().zip(v.rows.iter()) {
+                        row[column] = v_row[i];
+                    }
+                }
+                (eigenvalues, vectors)
+            }
+        }
+
+        /// Diagonalizes a symmetric matrix, returning its eigenvalues in descending order, and the orthogonal matrix
+        /// whose columns are the matching eigenvectors, with a determinant of +1.
+        fn jacobi_eigen(a: $generic<3, 3>) -> ([$scalar; 3], $generic<3, 3>) {
+            let (eigenvalues, mut vectors) = a.symmetric_eigen();
+            if $matrix3x3::from(vectors).determinant() < 0.0 {
+                for row in vectors.rows.iter_mut() {
+                    row[2] = -row[2];
+                }
+            }
+            (eigenvalues, vectors)
+        }
+
+        /// Calculates an SVD where `u` and `v` are both proper rotations. The last singular value is negative when the
+        /// determinant of the matrix is.
+        fn signed_svd(m: $generic<3, 3>) -> ($generic<3, 3>, [$scalar; 3], $generic<3, 3>) {
+            let (_, v) = jacobi_eigen(m.transpose() * m);
+            // The columns of m * v are orthogonal, so the R of its QR decomposition is diagonal.
+            let qr = (m * v).qr();
+            let mut u = qr.q();
+            let r = qr.r();
+            let mut singular_values = [r.rows[0][0], r.rows[1][1], r.rows[2][2]];
+            for i in 0..2 {
+                if singular_values[i] < 0.0 {
+                    singular_values[i] = -singular_values[i];
+                    singular_values[2] = -singular_values[2];
+                    for row in u.rows.iter_mut() {
+                        row[i] = -row[i];
+                        row[2] = -row[2];
+                    }
+                }
+            }
+            if $matrix3x3::from(u).determinant() < 0.0 {
+                singular_values[2] = -singular_values[2];
+                for row in u.rows.iter_mut() {
+                    row[2] = -row[2];
+                }
+            }
+            (u, singular_values, v)
+        }
+    };
+}
+
+mod single {
+    use super::*;
+    define_spectral3x3!(Matrix3x3, Matrix, Vector3, SymmetricEigen, Svd, f32);
+}
+pub use self::single::{SymmetricEigen, Svd};
+
+mod double {
+    use super::*;
+    define_spectral3x3!(DMatrix3x3, DMatrix, DVector3, DSymmetricEigen, DSvd, f64);
+}
+pub use self::double::{DSymmetricEigen, DSvd};
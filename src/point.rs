@@ -0,0 +1,211 @@
+use crate::Scalar;
+use crate::matrix::{Affine2D, Matrix3x3, Matrix4x4, DAffine2D, DMatrix3x3, DMatrix4x4};
+use crate::quaternion::{Quaternion, DQuaternion};
+use crate::vector::*;
+
+macro_rules! impl_point_vector_ops {
+    ($type: ident, $vector: ident, $($field:tt),+) => {
+        impl std::ops::Add<$vector> for $type {
+            type Output = $type;
+
+            fn add(self, rhs: $vector) -> $type {
+                $type { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl std::ops::AddAssign<$vector> for $type {
+            fn add_assign(&mut self, rhs: $vector) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl std::ops::Sub<$vector> for $type {
+            type Output = $type;
+
+            fn sub(self, rhs: $vector) -> $type {
+                $type { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl std::ops::SubAssign<$vector> for $type {
+            fn sub_assign(&mut self, rhs: $vector) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl std::ops::Sub for $type {
+            type Output = $vector;
+
+            fn sub(self, rhs: $type) -> $vector {
+                $vector { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl From<$vector> for $type {
+            fn from(vector: $vector) -> Self {
+                $type { $($field: vector.$field),+ }
+            }
+        }
+
+        impl From<$type> for $vector {
+            fn from(point: $type) -> Self {
+                $vector { $($field: point.$field),+ }
+            }
+        }
+    };
+}
+
+macro_rules! define_point2 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector2: ident, $affine2d: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+        }
+
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " at (x, y).")]
+            pub const fn new(x: $scalar, y: $scalar) -> Self {
+                $type { x, y }
+            }
+
+            /// Returns the point at (0.0, 0.0).
+            pub const fn origin() -> Self {
+                $type { x: 0.0, y: 0.0 }
+            }
+
+            /// Returns the distance between this point and the point 'rhs'.
+            pub fn distance(&self, rhs: &$type) -> $scalar {
+                (*rhs - *self).length()
+            }
+        }
+        impl_point_vector_ops!($type, $vector2, x, y);
+        impl_approx!($type; $scalar; x, y);
+
+        impl std::ops::Mul<$affine2d> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $affine2d) -> $type {
+                rhs.transform_point2(&self.into()).into()
+            }
+        }
+
+        impl std::ops::MulAssign<$affine2d> for $type {
+            fn mul_assign(&mut self, rhs: $affine2d) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+macro_rules! define_point3 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $matrix3x3: ident, $matrix4x4: ident, $quaternion: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+            pub z: $scalar,
+        }
+
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " at (x, y, z).")]
+            pub const fn new(x: $scalar, y: $scalar, z: $scalar) -> Self {
+                $type { x, y, z }
+            }
+
+            /// Returns the point at (0.0, 0.0, 0.0).
+            pub const fn origin() -> Self {
+                $type { x: 0.0, y: 0.0, z: 0.0 }
+            }
+
+            /// Returns the distance between this point and the point 'rhs'.
+            pub fn distance(&self, rhs: &$type) -> $scalar {
+                (*rhs - *self).length()
+            }
+        }
+        impl_point_vector_ops!($type, $vector3, x, y, z);
+        impl_approx!($type; $scalar; x, y, z);
+
+        impl std::ops::Mul<$matrix3x3> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $matrix3x3) -> $type {
+                ($vector3::from(self) * rhs).into()
+            }
+        }
+
+        impl std::ops::MulAssign<$matrix3x3> for $type {
+            fn mul_assign(&mut self, rhs: $matrix3x3) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl std::ops::Mul<$matrix4x4> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $matrix4x4) -> $type {
+                rhs.transform_point3(&self.into()).into()
+            }
+        }
+
+        impl std::ops::MulAssign<$matrix4x4> for $type {
+            fn mul_assign(&mut self, rhs: $matrix4x4) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl std::ops::Mul<$quaternion> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $quaternion) -> $type {
+                ($vector3::from(self) * rhs).into()
+            }
+        }
+
+        impl std::ops::MulAssign<$quaternion> for $type {
+            fn mul_assign(&mut self, rhs: $quaternion) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+define_point2!(
+    /// A position in 2-dimensional space.
+    ///
+    /// Subtracting two points gives the `Vector2` between them, and a `Vector2` can be added to or subtracted from a point.
+    /// Adding two points together is not allowed, as it has no meaning.
+    ///
+    /// Multiplying a point by an `Affine2D` applies the translation.
+    Point2, Scalar, Vector2, Affine2D
+);
+define_point3!(
+    /// A position in 3-dimensional space.
+    ///
+    /// Subtracting two points gives the `Vector3` between them, and a `Vector3` can be added to or subtracted from a point.
+    /// Adding two points together is not allowed, as it has no meaning:
+    /// ```compile_fail
+    /// use ezcgmath::point::Point3;
+    /// let _ = Point3::origin() + Point3::origin();
+    /// ```
+    ///
+    /// Multiplying a point by a `Matrix4x4` applies the translation. To transform a direction without it,
+    /// use a `Vector3` with `Matrix4x4::transform_vector3`.
+    Point3, Scalar, Vector3, Matrix3x3, Matrix4x4, Quaternion
+);
+
+define_point2!(
+    /// A position in 2-dimensional space, with double precision. See `Point2` for details.
+    DPoint2, f64, DVector2, DAffine2D
+);
+define_point3!(
+    /// A position in 3-dimensional space, with double precision. See `Point3` for details.
+    DPoint3, f64, DVector3, DMatrix3x3, DMatrix4x4, DQuaternion
+);
+
+impl_precision_conversion!(Point2, DPoint2, x, y);
+impl_precision_conversion!(Point3, DPoint3, x, y, z);
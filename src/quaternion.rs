@@ -1,180 +1,487 @@
-use crate::{Scalar, Degrees, Radians};
-use crate::vector::Vector3;
-use crate::matrix::{Matrix3x3, Matrix4x4};
-
-/// A Quaternion is used to represent a rotation. By representing a rotation this way,
-/// we can prevent gimbal locking and have better interpolation between different orientations
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct Quaternion {
-    pub x: Scalar,
-    pub y: Scalar,
-    pub z: Scalar,
-    pub w: Scalar,
-}
-impl_approx!(Quaternion, x, y, z, w);
-
-impl Quaternion {
-    /// Create an instance of the 'default' rotation
-    pub fn identity() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 1.0,
+use crate::{AngleScalar, Error, Scalar, Degrees};
+use crate::vector::{Vector3, DVector3};
+use crate::matrix::{Matrix, Matrix3x3, Matrix4x4, DMatrix, DMatrix3x3, DMatrix4x4};
+
+macro_rules! define_quaternion {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $matrix3x3: ident, $matrix4x4: ident, $generic: ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+            pub z: $scalar,
+            pub w: $scalar,
         }
-    }
-
-    /// Create a rotation of a given angle around a given axis
-    pub fn from_axis_angle(axis: &Vector3, angle: Degrees) -> Self {
-        let mut result = Quaternion {
-            x: axis.x * (Radians::from(angle).0 / 2.0).sin(),
-            y: axis.y * (Radians::from(angle).0 / 2.0).sin(),
-            z: axis.z * (Radians::from(angle).0 / 2.0).sin(),
-            w: (Radians::from(angle).0 / 2.0).cos()
-        };
-        result.normalize();
-        result
-    }
-
-    /// Create a rotation that points in a given forward and direction, with a defined upwards direction
-    pub fn from_look_at(forward: &Vector3, up: &Vector3) -> Self {
-        let mat = Matrix3x3::from_look_at(*forward, *up);
-        let tr = mat.c00 + mat.c11 + mat.c22;
-        let mut result = {
-            if tr >= 0.0 {
-                let s = (tr + 1.0).sqrt() * 2.0;
-                Self {
-                    x: (mat.c21 - mat.c12) / s,
-                    y: (mat.c02 - mat.c20) / s,
-                    z: (mat.c10 - mat.c01) / s,
-                    w: 0.25 * s
-                }
-            } else if (mat.c00 > mat.c11) && (mat.c00 > mat.c22) {
-                // TODO: Test Code Coverage missing for this
-                let s = (1.0 + mat.c00 - mat.c11 - mat.c22).sqrt() * 2.0;
-                Self {
-                    x: 0.25 * s,
-                    y: (mat.c01 + mat.c10) / s,
-                    z: (mat.c02 + mat.c20) / s,
-                    w: (mat.c21 - mat.c12) / s,
-                }
-            } else if mat.c11 > mat.c22 {
-                // TODO: Test Code Coverage missing for this
-                let s = (1.0 + mat.c11 - mat.c00 - mat.c22).sqrt() * 2.0;
-                Self {
-                    x: (mat.c01 + mat.c10) / s,
-                    y: 0.25 * s,
-                    z: (mat.c12 + mat.c21) / s,
-                    w: (mat.c02 - mat.c20) / s,
-                }
-            } else {
-                // TODO: Test Code Coverage missing for this
-                let s = (1.0 + mat.c22 - mat.c00 - mat.c11).sqrt() * 2.0;
+        impl_approx!($type; $scalar; x, y, z, w);
+
+        impl $type {
+            /// Create an instance of the 'default' rotation
+            pub fn identity() -> Self {
                 Self {
-                    x: (mat.c02 + mat.c20) / s,
-                    y: (mat.c12 + mat.c21) / s,
-                    z: 0.25 * s,
-                    w: (mat.c10 - mat.c01) / s,
-                }
-            }
-        };
-        result.normalize();
-        result
-    }
-
-    /// Create a rotation that rotates x, y, and z degrees around each axis.
-    pub fn from_euler(angle_x: Degrees, angle_y: Degrees, angle_z: Degrees) -> Self {
-        let angle_x = Radians::from(angle_x).0 * 0.5;
-        let angle_y = Radians::from(angle_y).0 * 0.5;
-        let angle_z = Radians::from(angle_z).0 * 0.5;
-
-        let cy = angle_z.cos();
-        let sy = angle_z.sin();
-        let cr = angle_x.cos();
-        let sr = angle_x.sin();
-        let cp = angle_y.cos();
-        let sp = angle_y.sin();
-    
-        let mut result = Quaternion {
-            x: cy * sr * cp - sy * cr * sp,
-            y: cy * cr * sp + sy * sr * cp,
-            z: sy * cr * cp - cy * sr * sp,
-            w: cy * cr * cp + sy * sr * sp,
-        };
-        result.normalize();
-        result
-    }
-
-    // TODO: as_euler
-    // /// Convert this quaternion into a euler representation
-    // pub fn as_euler(self) -> (Degrees, Degrees, Degrees) {
-    //     let sinr = 2.0 * (self.w * self.x + self.y * self.z);
-    //     let cosr = 1.0 - 2.0 * (self.x * self.x + self.y * self.y);
-    //     let x = sinr.atan2(cosr);
-    
-    //     let y = {
-    //         let sinp = 2.0 * (self.w * self.y - self.z * self.x);
-    //         if sinp.abs() >= 1.0 {
-    //             (std::f32::consts::PI / 2.0).copysign(sinp/sinp)
-    //         } else {
-    //             sinp.asin()
-    //         }
-    //     };
-    
-    //     let siny = 2.0 * (self.w * self.z + self.x * self.y);
-    //     let cosy = 1.0 - 2.0 * (self.y * self.y + self.z * self.z);
-    //     let z = siny.atan2(cosy);
-        
-    //     (Degrees::from(Radians(x)), Degrees::from(Radians(y)), Degrees::from(Radians(z)))
-    // }
-
-    fn normalize(&mut self) {
-        let mag = self.magnitude();
-        self.x /= mag;
-        self.y /= mag;
-        self.z /= mag;
-        self.w /= mag;
-    }
-
-    fn magnitude(&self) -> Scalar {
-        return (self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0) + self.w.powf(2.0)).sqrt();
-    }
-}
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    w: 1.0,
+                }
+            }
+
+            /// Create a rotation of a given angle around a given axis
+            pub fn from_axis_angle(axis: &$vector3, angle: Degrees) -> Self {
+                let mut result = $type {
+                    x: axis.x * (<$scalar as AngleScalar>::radians(angle) / 2.0).sin(),
+                    y: axis.y * (<$scalar as AngleScalar>::radians(angle) / 2.0).sin(),
+                    z: axis.z * (<$scalar as AngleScalar>::radians(angle) / 2.0).sin(),
+                    w: (<$scalar as AngleScalar>::radians(angle) / 2.0).cos()
+                };
+                result.normalize();
+                result
+            }
+
+            /// Create a rotation that points in a given forward and direction, with a defined upwards direction
+            pub fn from_look_at(forward: &$vector3, up: &$vector3) -> Self {
+                Self::from_rotation_matrix(&$matrix3x3::from_look_at(*forward, *up).transpose())
+            }
+
+            #[doc = concat!("Create the rotation described by a rotation matrix, so that `", stringify!($matrix4x4), "::from` turns it back into the same matrix.")]
+            ///
+            /// The matrix is expected to be a rotation, without any scale, skew or reflection.
+            /// Use `nearest_rotation` first if that might not be the case.
+            pub fn from_rotation_matrix(mat: &$matrix3x3) -> Self {
+                let tr = mat.c00 + mat.c11 + mat.c22;
+                let mut result = {
+                    if tr >= 0.0 {
+                        let s = (tr + 1.0).sqrt() * 2.0;
+                        Self {
+                            x: (mat.c12 - mat.c21) / s,
+                            y: (mat.c20 - mat.c02) / s,
+                            z: (mat.c01 - mat.c10) / s,
+                            w: 0.25 * s
+                        }
+                    } else if (mat.c00 > mat.c11) && (mat.c00 > mat.c22) {
+                        let s = (1.0 + mat.c00 - mat.c11 - mat.c22).sqrt() * 2.0;
+                        Self {
+                            x: 0.25 * s,
+                            y: (mat.c01 + mat.c10) / s,
+                            z: (mat.c02 + mat.c20) / s,
+                            w: (mat.c12 - mat.c21) / s,
+                        }
+                    } else if mat.c11 > mat.c22 {
+                        let s = (1.0 + mat.c11 - mat.c00 - mat.c22).sqrt() * 2.0;
+                        Self {
+                            x: (mat.c01 + mat.c10) / s,
+                            y: 0.25 * s,
+                            z: (mat.c12 + mat.c21) / s,
+                            w: (mat.c20 - mat.c02) / s,
+                        }
+                    } else {
+                        let s = (1.0 + mat.c22 - mat.c00 - mat.c11).sqrt() * 2.0;
+                        Self {
+                            x: (mat.c02 + mat.c20) / s,
+                            y: (mat.c12 + mat.c21) / s,
+                            z: 0.25 * s,
+                            w: (mat.c01 - mat.c10) / s,
+                        }
+                    }
+                };
+                result.normalize();
+                result
+            }
+
+            /// Create the shortest rotation that turns the direction `from` into the direction `to`, so that
+            /// `from * rotation` points along `to`. Neither vector needs to be normalized, but neither may have a length of zero.
+            ///
+            /// When the vectors point in opposite directions, there is no single shortest rotation,
+            /// so this rotates half a turn around an arbitrary axis perpendicular to `from`.
+            pub fn from_rotation_arc(from: &$vector3, to: &$vector3) -> Self {
+                let mut from = *from;
+                let mut to = *to;
+                from.normalize();
+                to.normalize();
+                let w = 1.0 + from.dot(&to);
+                let mut result = if w <= <$scalar>::EPSILON {
+                    let axis = from.any_orthogonal();
+                    Self { x: axis.x, y: axis.y, z: axis.z, w: 0.0 }
+                } else {
+                    // Vectors rotate by the inverse of the textbook convention, so the axis is `to` cross `from`.
+                    let axis = to.cross(&from);
+                    Self { x: axis.x, y: axis.y, z: axis.z, w }
+                };
+                result.normalize();
+                result
+            }
+
+            /// Splits this rotation into a swing and a twist around `axis`, returned as `(swing, twist)`, such that
+            /// `twist * swing == self`. The axis is expected to be normalized.
+            ///
+            /// The twist rotates around the axis, and is applied first. The swing then rotates around an axis
+            /// perpendicular to it, so `axis * swing == axis * self`. Joint limits are usually expressed this way,
+            /// with the axis pointing along the bone. The twist is returned with a non-negative `w`.
+            pub fn swing_twist(&self, axis: &$vector3) -> ($type, $type) {
+                let projection = self.x * axis.x + self.y * axis.y + self.z * axis.z;
+                let mut twist = $type { x: axis.x * projection, y: axis.y * projection, z: axis.z * projection, w: self.w };
+                if twist.try_normalize(<$scalar>::EPSILON).is_err() {
+                    // A half turn swing leaves no twist to measure, so all of the rotation is swing.
+                    twist = Self::identity();
+                }
+                if twist.w < 0.0 {
+                    twist = $type { x: -twist.x, y: -twist.y, z: -twist.z, w: -twist.w };
+                }
+                (twist.conjugate() * *self, twist)
+            }
+
+            /// Limits the twist of this rotation around `axis` to between `min` and `max`, keeping the swing.
+            /// The axis is expected to be normalized, and a positive angle twists the same way as `from_axis_angle`.
+            pub fn clamp_twist(&self, axis: &$vector3, min: Degrees, max: Degrees) -> Self {
+                let (swing, twist) = self.swing_twist(axis);
+                let projection = twist.x * axis.x + twist.y * axis.y + twist.z * axis.z;
+                let angle = 2.0 * projection.atan2(twist.w);
+                let min = <$scalar as AngleScalar>::radians(min);
+                let max = <$scalar as AngleScalar>::radians(max);
+                if angle >= min && angle <= max {
+                    return *self;
+                }
+                Self::from_axis_radians(axis, angle.max(min).min(max)) * swing
+            }
+
+            /// Limits the swing of this rotation away from `axis` to a cone with a half angle of `max_angle`,
+            /// keeping the twist. The axis is expected to be normalized.
+            pub fn clamp_swing_cone(&self, axis: &$vector3, max_angle: Degrees) -> Self {
+                let (swing, twist) = self.swing_twist(axis);
+                let mut swing_axis = $vector3::new(swing.x, swing.y, swing.z) * swing.w.signum();
+                let angle = 2.0 * swing_axis.length().atan2(swing.w.abs());
+                let max_angle = <$scalar as AngleScalar>::radians(max_angle);
+                if angle <= max_angle {
+                    return *self;
+                }
+                swing_axis.normalize();
+                twist * Self::from_axis_radians(&swing_axis, max_angle)
+            }
+
+            /// Limits the swing of this rotation away from `axis` to an elliptical cone, keeping the twist.
+            ///
+            /// `reference` is perpendicular to `axis`, and both are expected to be normalized. The swing may rotate up to
+            /// `max_angle_reference` around `reference`, and up to `max_angle_other` around `axis.cross(reference)`.
+            /// A swing outside the cone is scaled back towards the axis until it lies on the edge.
+            ///
+            /// A limit of zero on one of the two axes restricts the swing to a plane: the swing around that axis is
+            /// removed, and the swing around the other axis is clamped to its limit.
+            pub fn clamp_swing_ellipse(&self, axis: &$vector3, reference: &$vector3, max_angle_reference: Degrees, max_angle_other: Degrees) -> Self {
+                let (swing, twist) = self.swing_twist(axis);
+                let mut swing_axis = $vector3::new(swing.x, swing.y, swing.z) * swing.w.signum();
+                let sin_half = swing_axis.length();
+                if sin_half <= <$scalar>::EPSILON {
+                    return *self;
+                }
+                swing_axis /= sin_half;
+                let angle = 2.0 * sin_half.atan2(swing.w.abs());
+                let other = axis.cross(reference);
+                let angle_reference = angle * swing_axis.dot(reference);
+                let angle_other = angle * swing_axis.dot(&other);
+                let max_reference = <$scalar as AngleScalar>::radians(max_angle_reference).abs();
+                let max_other = <$scalar as AngleScalar>::radians(max_angle_other).abs();
+                let (clamped_reference, clamped_other) = if max_reference == 0.0 || max_other == 0.0 {
+                    // The ellipse collapses onto a line, so each angle is clamped on its own.
+                    (angle_reference.clamp(-max_reference, max_reference), angle_other.clamp(-max_other, max_other))
+                } else {
+                    let scaled_reference = angle_reference / max_reference;
+                    let scaled_other = angle_other / max_other;
+                    let distance = (scaled_reference * scaled_reference + scaled_other * scaled_other).sqrt();
+                    if distance <= 1.0 {
+                        return *self;
+                    }
+                    (angle_reference / distance, angle_other / distance)
+                };
+                if clamped_reference == angle_reference && clamped_other == angle_other {
+                    return *self;
+                }
+                twist * Self::from_scaled_axis(&(*reference * clamped_reference + other * clamped_other))
+            }
 
-impl std::ops::Mul for Quaternion {
-    type Output = Quaternion;
+            /// Calculates the weighted average of many rotations, given as `(rotation, weight)` pairs.
+            ///
+            /// This is the method of Markley et al., "Averaging Quaternions" (2007), which finds the rotation that
+            /// minimizes the weighted sum of squared distances to the inputs. Unlike blending with repeated `slerp`,
+            /// the result does not depend on the order of the inputs. The rotations are expected to be normalized, and
+            /// the weights non-negative. If the weights add up to zero or less, the result is `identity`.
+            ///
+            /// The result is in the same hemisphere as the first rotation, so that blending stays continuous.
+            /// For inputs that are close together, `weighted_nlerp` gives nearly the same result at a lower cost.
+            pub fn weighted_average(rotations: &[($type, $scalar)]) -> Self {
+                let total = rotations.iter().map(|(_, weight)| weight).sum::<$scalar>();
+                if total <= 0.0 {
+                    return Self::identity();
+                }
+                let mut accumulated = $generic::<4, 4>::zero();
+                for (rotation, weight) in rotations {
+                    let q = [rotation.x, rotation.y, rotation.z, rotation.w];
+                    for (i, row) in accumulated.rows.iter_mut().enumerate() {
+                        for (j, element) in row.iter_mut().enumerate() {
+                            *element += weight * q[i] * q[j];
+                        }
+                    }
+                }
+                // The eigenvector with the largest eigenvalue maximizes the weighted sum of squared dot products.
+                let (_, eigenvectors) = accumulated.symmetric_eigen();
+                let column = eigenvectors.column(0);
+                let mut result = Self { x: column[0], y: column[1], z: column[2], w: column[3] };
+                let first = rotations[0].0;
+                if result.dot(&first) < 0.0 {
+                    result = Self { x: -result.x, y: -result.y, z: -result.z, w: -result.w };
+                }
+                result.normalize();
+                result
+            }
 
-    fn mul(self, rhs: Quaternion) -> Quaternion {
-        Quaternion {
-            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
-            y: self.w * rhs.y + self.y * rhs.w + self.z * rhs.x - self.x * rhs.z,
-            z: self.w * rhs.z + self.z * rhs.w + self.x * rhs.y - self.y * rhs.x,
-            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            /// Approximates the weighted average of many rotations, given as `(rotation, weight)` pairs, by adding them
+            /// up and normalizing the sum.
+            ///
+            /// Each rotation is first flipped into the hemisphere of the first rotation, so that `q` and `-q` count
+            /// as the same rotation. This is accurate when the rotations are within a few tens of degrees of each other,
+            /// as with the poses of an animation blend tree, and much cheaper than `weighted_average`.
+            /// The weights are expected to be non-negative. If they add up to zero or less, or the rotations cancel
+            /// each other out so that their sum is nearly zero, the result is `identity`.
+            pub fn weighted_nlerp(rotations: &[($type, $scalar)]) -> Self {
+                let total = rotations.iter().map(|(_, weight)| weight).sum::<$scalar>();
+                if total <= 0.0 {
+                    return Self::identity();
+                }
+                let first = rotations[0].0;
+                let mut result = $type { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+                for (rotation, weight) in rotations {
+                    let weight = if rotation.dot(&first) < 0.0 { -weight } else { *weight };
+                    result.x += rotation.x * weight;
+                    result.y += rotation.y * weight;
+                    result.z += rotation.z * weight;
+                    result.w += rotation.w * weight;
+                }
+                if result.try_normalize(<$scalar>::EPSILON).is_err() {
+                    return Self::identity();
+                }
+                result
+            }
+
+            /// Create a rotation from a rotation vector, whose direction is the axis and whose length is the angle in radians.
+            ///
+            /// This is the exponential map. A vector of length zero results in `identity`.
+            pub fn from_scaled_axis(rotation: &$vector3) -> Self {
+                let angle = rotation.length();
+                // sin(angle / 2) / angle, with its limit of 1 / 2 for small angles.
+                let scale = if angle > <$scalar>::EPSILON.sqrt() { (angle * 0.5).sin() / angle } else { 0.5 - angle * angle / 48.0 };
+                let mut result = Self { x: rotation.x * scale, y: rotation.y * scale, z: rotation.z * scale, w: (angle * 0.5).cos() };
+                result.normalize();
+                result
+            }
+
+            /// Converts this rotation into a rotation vector, whose direction is the axis and whose length is the angle
+            /// in radians, taking the shorter way around. The quaternion is expected to be normalized.
+            ///
+            /// This is the logarithm map, and the inverse of `from_scaled_axis`.
+            pub fn to_scaled_axis(&self) -> $vector3 {
+                let (vector, w) = if self.w < 0.0 {
+                    ($vector3::new(-self.x, -self.y, -self.z), -self.w)
+                } else {
+                    ($vector3::new(self.x, self.y, self.z), self.w)
+                };
+                let sin_half = vector.length();
+                let angle = 2.0 * sin_half.atan2(w);
+                if sin_half <= <$scalar>::EPSILON {
+                    return vector * 2.0;
+                }
+                vector * (angle / sin_half)
+            }
+
+            /// Rotates this orientation by an angular velocity, in radians per second, over `dt` seconds.
+            ///
+            /// The angular velocity is in world space, the space this rotation maps into, so the result is
+            /// `self * Self::from_scaled_axis(&(angular_velocity * dt))`. For an angular velocity in local space,
+            /// transform it into world space with `local * self` first. This is exact for a constant angular velocity,
+            /// and the result stays normalized.
+            pub fn integrate(&self, angular_velocity: &$vector3, dt: $scalar) -> Self {
+                let mut result = *self * Self::from_scaled_axis(&(*angular_velocity * dt));
+                result.normalize();
+                result
+            }
+
+            /// Rotates this orientation by an angular velocity over `dt` seconds with a single explicit Euler step,
+            /// `self + self.derivative(angular_velocity) * dt`, normalizing the result.
+            ///
+            /// This is cheaper than `integrate` and agrees with it for small rotations per step, but it
+            /// undershoots the angle as the rotation per step grows.
+            pub fn integrate_first_order(&self, angular_velocity: &$vector3, dt: $scalar) -> Self {
+                let derivative = self.derivative(angular_velocity);
+                let mut result = Self {
+                    x: self.x + derivative.x * dt,
+                    y: self.y + derivative.y * dt,
+                    z: self.z + derivative.z * dt,
+                    w: self.w + derivative.w * dt,
+                };
+                result.normalize();
+                result
+            }
+
+            /// Calculates the rate of change of this quaternion while rotating with an angular velocity in world space,
+            /// in radians per second. See `integrate` for the convention.
+            pub fn derivative(&self, angular_velocity: &$vector3) -> Self {
+                let velocity = Self { x: angular_velocity.x * 0.5, y: angular_velocity.y * 0.5, z: angular_velocity.z * 0.5, w: 0.0 };
+                *self * velocity
+            }
+
+            /// Calculates the angular velocity in world space from the rate of change of this quaternion.
+            /// This is the inverse of `derivative`, and the quaternion is expected to be normalized.
+            pub fn angular_velocity_from_derivative(&self, derivative: &$type) -> $vector3 {
+                let velocity = self.conjugate() * *derivative;
+                $vector3::new(velocity.x * 2.0, velocity.y * 2.0, velocity.z * 2.0)
+            }
+
+            /// Calculates the constant angular velocity in world space that rotates this orientation into `target`
+            /// in `dt` seconds, taking the shorter way around, so that `self.integrate(&velocity, dt)` equals `target`.
+            /// Both quaternions are expected to be normalized.
+            pub fn angular_velocity_to(&self, target: &$type, dt: $scalar) -> $vector3 {
+                (self.conjugate() * *target).to_scaled_axis() / dt
+            }
+
+            /// Create a rotation that rotates x, y, and z degrees around each axis.
+            pub fn from_euler(angle_x: Degrees, angle_y: Degrees, angle_z: Degrees) -> Self {
+                let angle_x = <$scalar as AngleScalar>::radians(angle_x) * 0.5;
+                let angle_y = <$scalar as AngleScalar>::radians(angle_y) * 0.5;
+                let angle_z = <$scalar as AngleScalar>::radians(angle_z) * 0.5;
+
+                let cy = angle_z.cos();
+                let sy = angle_z.sin();
+                let cr = angle_x.cos();
+                let sr = angle_x.sin();
+                let cp = angle_y.cos();
+                let sp = angle_y.sin();
+
+                let mut result = $type {
+                    x: cy * sr * cp - sy * cr * sp,
+                    y: cy * cr * sp + sy * sr * cp,
+                    z: sy * cr * cp - cy * sr * sp,
+                    w: cy * cr * cp + sy * sr * sp,
+                };
+                result.normalize();
+                result
+            }
+
+            // TODO: as_euler
+            // /// Convert this quaternion into a euler representation
+            // pub fn as_euler(self) -> (Degrees, Degrees, Degrees) {
+            //     let sinr = 2.0 * (self.w * self.x + self.y * self.z);
+            //     let cosr = 1.0 - 2.0 * (self.x * self.x + self.y * self.y);
+            //     let x = sinr.atan2(cosr);
+
+            //     let y = {
+            //         let sinp = 2.0 * (self.w * self.y - self.z * self.x);
+            //         if sinp.abs() >= 1.0 {
+            //             (std::f32::consts::PI / 2.0).copysign(sinp/sinp)
+            //         } else {
+            //             sinp.asin()
+            //         }
+            //     };
+
+            //     let siny = 2.0 * (self.w * self.z + self.x * self.y);
+            //     let cosy = 1.0 - 2.0 * (self.y * self.y + self.z * self.z);
+            //     let z = siny.atan2(cosy);
+
+            //     (Degrees::from(Radians(x)), Degrees::from(Radians(y)), Degrees::from(Radians(z)))
+            // }
+
+            /// Returns the dot product of the quaternion with the quaternion 'rhs'.
+            ///
+            /// For normalized quaternions, this is the cosine of half the angle between the two rotations.
+            pub fn dot(&self, rhs: &$type) -> $scalar {
+                self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+            }
+
+            /// Returns the conjugate, which is the inverse rotation when the quaternion is normalized.
+            pub fn conjugate(&self) -> Self {
+                Self { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+            }
+
+            /// Normalizes the quaternion to a length of one, unless its length is less than or equal to `tolerance`.
+            ///
+            /// This returns `Error::ZeroLength` and leaves the quaternion unchanged rather than filling it with NaN.
+            pub fn try_normalize(&mut self, tolerance: $scalar) -> Result<(), Error> {
+                let mag = self.magnitude();
+                if mag.is_nan() || mag <= tolerance {
+                    return Err(Error::ZeroLength);
+                }
+                self.normalize();
+                Ok(())
+            }
+
+            /// Returns a normalized copy of the quaternion, or `None` if its length is less than or equal to `tolerance`.
+            pub fn checked_normalize(&self, tolerance: $scalar) -> Option<$type> {
+                let mut result = *self;
+                result.try_normalize(tolerance).ok().map(|_| result)
+            }
+
+            fn from_axis_radians(axis: &$vector3, angle: $scalar) -> Self {
+                let (sin, cos) = (angle * 0.5).sin_cos();
+                Self { x: axis.x * sin, y: axis.y * sin, z: axis.z * sin, w: cos }
+            }
+
+            fn normalize(&mut self) {
+                let mag = self.magnitude();
+                self.x /= mag;
+                self.y /= mag;
+                self.z /= mag;
+                self.w /= mag;
+            }
+
+            fn magnitude(&self) -> $scalar {
+                (self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0) + self.w.powf(2.0)).sqrt()
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign for Quaternion {
-    fn mul_assign(&mut self, rhs: Quaternion) {
-        let x = self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y;
-        let y = self.w * rhs.y + self.y * rhs.w + self.z * rhs.x - self.x * rhs.z;
-        let z = self.w * rhs.z + self.z * rhs.w + self.x * rhs.y - self.y * rhs.x;
-        let w = self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z;
-
-        self.x = x;
-        self.y = y;
-        self.z = z;
-        self.w = w;
-    }
-}
+        impl std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type {
+                    x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+                    y: self.w * rhs.y + self.y * rhs.w + self.z * rhs.x - self.x * rhs.z,
+                    z: self.w * rhs.z + self.z * rhs.w + self.x * rhs.y - self.y * rhs.x,
+                    w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+                }
+            }
+        }
 
-impl std::ops::Mul<Matrix4x4> for Quaternion {
-    type Output = Matrix4x4;
+        impl std::ops::MulAssign for $type {
+            fn mul_assign(&mut self, rhs: $type) {
+                let x = self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y;
+                let y = self.w * rhs.y + self.y * rhs.w + self.z * rhs.x - self.x * rhs.z;
+                let z = self.w * rhs.z + self.z * rhs.w + self.x * rhs.y - self.y * rhs.x;
+                let w = self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z;
+
+                self.x = x;
+                self.y = y;
+                self.z = z;
+                self.w = w;
+            }
+        }
 
-    fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
-        Matrix4x4::from(self) * rhs
-    }
+        impl std::ops::Mul<$matrix4x4> for $type {
+            type Output = $matrix4x4;
+
+            fn mul(self, rhs: $matrix4x4) -> $matrix4x4 {
+                $matrix4x4::from(self) * rhs
+            }
+        }
+    };
 }
 
+define_quaternion!(
+    /// A Quaternion is used to represent a rotation. By representing a rotation this way,
+    /// we can prevent gimbal locking and have better interpolation between different orientations
+    Quaternion, Scalar, Vector3, Matrix3x3, Matrix4x4, Matrix
+);
+define_quaternion!(
+    /// A Quaternion with double precision. See `Quaternion` for details.
+    DQuaternion, f64, DVector3, DMatrix3x3, DMatrix4x4, DMatrix
+);
+
+impl_precision_conversion!(Quaternion, DQuaternion, x, y, z, w);
+
 // TODO: Add Matrix4x4 -> Quaternion Conversion?
 // Then We could implement std::ops::MulAssign<Matrix4x4> for Quaternion
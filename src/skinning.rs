@@ -0,0 +1,130 @@
+use crate::Scalar;
+use crate::dual_quaternion::DualQuaternion;
+use crate::matrix::Matrix4x4;
+use crate::vector::Vector3;
+
+/// Scales the weights of every vertex so that they add up to one. Vertices whose weights add up to zero are left unchanged.
+///
+/// The skinning functions use the weights as they are, so call this first if your source data is not normalized,
+/// for example after dropping the smallest influences to fit into 4.
+pub fn normalize_weights<const N: usize>(weights: &mut [[Scalar; N]]) {
+    for vertex in weights.iter_mut() {
+        let total = vertex.iter().sum::<Scalar>();
+        if total != 0.0 {
+            for weight in vertex.iter_mut() {
+                *weight /= total;
+            }
+        }
+    }
+}
+
+/// Deforms vertices with linear blend skinning, where each vertex is transformed by the weighted sum of the matrices
+/// of the bones that influence it.
+///
+/// Vertex `i` is influenced by the bones `indices[i]`, which index into `palette`, with the matching `weights[i]`.
+/// `N` is the number of influences per vertex, usually 4 or 8. Influences with a weight of zero are skipped, so unused
+/// slots can hold any index. The weights are expected to add up to one, see `normalize_weights`.
+///
+/// Each palette matrix is usually the inverse bind matrix of the bone multiplied by its current transformation.
+/// Normals are transformed with `Matrix4x4::transform_normal` and normalized. Where the blended matrix is singular,
+/// for example because a bone is scaled to zero, they are transformed like directions instead. Pass empty `normals`
+/// and `skinned_normals` to only skin the positions.
+///
+/// A vertex whose weights add up to zero or less is not deformed, the same as in `skin_dual_quaternion`.
+///
+/// # Panics
+/// Panics if the slices for the vertices are not all the same length, or if an index with a non-zero weight is
+/// outside of the palette.
+pub fn skin_linear<const N: usize>(
+    positions: &[Vector3],
+    normals: &[Vector3],
+    indices: &[[u16; N]],
+    weights: &[[Scalar; N]],
+    palette: &[Matrix4x4],
+    skinned_positions: &mut [Vector3],
+    skinned_normals: &mut [Vector3],
+) {
+    check_lengths(positions, normals, indices, weights, skinned_positions, skinned_normals);
+    for i in 0..positions.len() {
+        let mut matrix = Matrix4x4::default();
+        for (index, weight) in indices[i].iter().zip(weights[i].iter()) {
+            if *weight != 0.0 {
+                matrix += palette[*index as usize] * *weight;
+            }
+        }
+        if weights[i].iter().sum::<Scalar>() <= 0.0 {
+            matrix = Matrix4x4::identity();
+        }
+        skinned_positions[i] = matrix.transform_point3(&positions[i]);
+        if !normals.is_empty() {
+            let normal = matrix
+                .try_transform_normal(&normals[i], Scalar::MIN_POSITIVE)
+                .unwrap_or_else(|_| matrix.transform_vector3(&normals[i]));
+            skinned_normals[i] = normalized(normal);
+        }
+    }
+}
+
+/// Deforms vertices with dual quaternion skinning, where each vertex is transformed by the blend of the rigid
+/// transformations of the bones that influence it, using `DualQuaternion::weighted_blend`.
+///
+/// See `DualQuaternion::weighted_blend` for how this differs from `skin_linear` around twisting joints. The bones
+/// cannot contain scale or skew, so convert a palette of rigid matrices with `DualQuaternion::from_matrix`.
+/// The arguments are otherwise the same as for `skin_linear`. A vertex whose weights add up to zero or less is
+/// not deformed.
+///
+/// # Panics
+/// Panics if the slices for the vertices are not all the same length, or if an index with a non-zero weight is
+/// outside of the palette.
+pub fn skin_dual_quaternion<const N: usize>(
+    positions: &[Vector3],
+    normals: &[Vector3],
+    indices: &[[u16; N]],
+    weights: &[[Scalar; N]],
+    palette: &[DualQuaternion],
+    skinned_positions: &mut [Vector3],
+    skinned_normals: &mut [Vector3],
+) {
+    check_lengths(positions, normals, indices, weights, skinned_positions, skinned_normals);
+    for i in 0..positions.len() {
+        let mut influences = [(DualQuaternion::identity(), 0.0); N];
+        for (influence, (index, weight)) in influences.iter_mut().zip(indices[i].iter().zip(weights[i].iter())) {
+            if *weight != 0.0 {
+                *influence = (palette[*index as usize], *weight);
+            }
+        }
+        // Blend in the hemisphere of the strongest bone, which is the first influence.
+        let strongest = (0..N).fold(0, |best, j| if influences[j].1 > influences[best].1 { j } else { best });
+        influences.swap(0, strongest);
+        let transformation = DualQuaternion::weighted_blend(&influences);
+        skinned_positions[i] = transformation.transform_point3(&positions[i]);
+        if !normals.is_empty() {
+            skinned_normals[i] = normalized(transformation.transform_vector3(&normals[i]));
+        }
+    }
+}
+
+fn check_lengths<const N: usize>(
+    positions: &[Vector3],
+    normals: &[Vector3],
+    indices: &[[u16; N]],
+    weights: &[[Scalar; N]],
+    skinned_positions: &[Vector3],
+    skinned_normals: &[Vector3],
+) {
+    let count = positions.len();
+    assert!(
+        indices.len() == count && weights.len() == count && skinned_positions.len() == count,
+        "every vertex needs indices, weights and an output position",
+    );
+    assert!(
+        normals.len() == skinned_normals.len() && (normals.is_empty() || normals.len() == count),
+        "normals must be empty, or one for each vertex with an output for each",
+    );
+}
+
+fn normalized(mut normal: Vector3) -> Vector3 {
+    // A degenerate normal is left as it is, rather than filled with NaN.
+    let _ = normal.try_normalize(0.0);
+    normal
+}
@@ -0,0 +1,271 @@
+//! Wrappers that tag vectors, points and matrices with the coordinate space they belong to, so that
+//! mixing up spaces becomes a compile error rather than a rendering bug.
+//!
+//! A space is any type, usually an empty enum. `World`, `Local` and `View` are provided, but you can declare your own:
+//! ```
+//! use ezcgmath::prelude::*;
+//! use ezcgmath::space::{Matrix4x4Between, Point3In, Local, World, View};
+//!
+//! pub enum Shadow {}
+//!
+//! let local_to_world = Matrix4x4Between::<Local, World>::new(Matrix4x4::from_translation(&Vector3::new(0.0, 5.0, 0.0)));
+//! let world_to_view = Matrix4x4Between::<World, View>::new(Matrix4x4::from_translation(&Vector3::new(0.0, 0.0, 10.0)));
+//! let local_to_view: Matrix4x4Between<Local, View> = local_to_world * world_to_view;
+//! let position: Point3In<View> = Point3In::<Local>::new(1.0, 0.0, 0.0) * local_to_view;
+//! ```
+//! Composing the transformations in the wrong order does not compile:
+//! ```compile_fail
+//! use ezcgmath::prelude::*;
+//! use ezcgmath::space::{Matrix4x4Between, Local, World, View};
+//!
+//! let local_to_world = Matrix4x4Between::<Local, World>::new(Matrix4x4::identity());
+//! let world_to_view = Matrix4x4Between::<World, View>::new(Matrix4x4::identity());
+//! let _ = world_to_view * local_to_world;
+//! ```
+//! Neither does mixing spaces in a calculation:
+//! ```compile_fail
+//! use ezcgmath::space::{Vector3In, Local, World};
+//!
+//! let _ = Vector3In::<World>::new(1.0, 0.0, 0.0).dot(&Vector3In::<Local>::new(0.0, 1.0, 0.0));
+//! ```
+//! ```compile_fail
+//! use ezcgmath::space::{Point3In, Local, World};
+//!
+//! let _ = Point3In::<World>::new(1.0, 0.0, 0.0).distance(&Point3In::<Local>::new(0.0, 1.0, 0.0));
+//! ```
+//! The wrappers have the same layout as the types they wrap, and the untagged types remain the default everywhere else.
+//! Use `vector`, `point` and `matrix` to get the untagged value back, when an operation is not available on the wrapper.
+
+use std::marker::PhantomData;
+
+use crate::Scalar;
+use crate::matrix::Matrix4x4;
+use crate::point::Point3;
+use crate::vector::Vector3;
+
+/// The space that a whole scene is laid out in.
+#[derive(Debug)]
+pub enum World {}
+
+/// The space of a single object, relative to its own origin.
+#[derive(Debug)]
+pub enum Local {}
+
+/// The space of a camera, relative to its position and orientation.
+#[derive(Debug)]
+pub enum View {}
+
+macro_rules! impl_tagged_traits {
+    ($type: ident<$($param:ident),+>, $field: ident) => {
+        impl<$($param),+> Clone for $type<$($param),+> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<$($param),+> Copy for $type<$($param),+> {}
+
+        impl<$($param),+> PartialEq for $type<$($param),+> {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<$($param),+> std::fmt::Debug for $type<$($param),+> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_tuple(stringify!($type)).field(&self.$field).finish()
+            }
+        }
+
+    };
+}
+
+/// A `Vector3` direction or offset in the space `S`.
+#[repr(transparent)]
+pub struct Vector3In<S> {
+    vector: Vector3,
+    space: PhantomData<fn() -> S>,
+}
+impl_tagged_traits!(Vector3In<S>, vector);
+
+impl<S> Vector3In<S> {
+    /// Creates a new vector in this space with values (x, y, z).
+    pub const fn new(x: Scalar, y: Scalar, z: Scalar) -> Self {
+        Self::from_vector(Vector3::new(x, y, z))
+    }
+
+    /// Tags an untagged vector as being in this space.
+    pub const fn from_vector(vector: Vector3) -> Self {
+        Self { vector, space: PhantomData }
+    }
+
+    /// Returns the untagged vector.
+    pub const fn vector(&self) -> Vector3 {
+        self.vector
+    }
+
+    /// Returns the dot product of the vector with the vector 'rhs', which must be in the same space.
+    pub fn dot(&self, rhs: &Self) -> Scalar {
+        self.vector.dot(&rhs.vector)
+    }
+
+    /// Returns the cross product of the vector with the vector 'rhs', which must be in the same space.
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self::from_vector(self.vector.cross(&rhs.vector))
+    }
+
+    /// The length of the vector.
+    pub fn length(&self) -> Scalar {
+        self.vector.length()
+    }
+
+    /// Normalizes the vector to a length of one. See `Vector3::normalize`.
+    pub fn normalize(&mut self) {
+        self.vector.normalize();
+    }
+}
+
+impl<S> std::ops::Add for Vector3In<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(self.vector + rhs.vector)
+    }
+}
+
+impl<S> std::ops::Sub for Vector3In<S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(self.vector - rhs.vector)
+    }
+}
+
+impl<S> std::ops::Mul<Scalar> for Vector3In<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Scalar) -> Self {
+        Self::from_vector(self.vector * rhs)
+    }
+}
+
+impl<S> std::ops::Neg for Vector3In<S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_vector(-self.vector)
+    }
+}
+
+/// A `Point3` position in the space `S`.
+#[repr(transparent)]
+pub struct Point3In<S> {
+    point: Point3,
+    space: PhantomData<fn() -> S>,
+}
+impl_tagged_traits!(Point3In<S>, point);
+
+impl<S> Point3In<S> {
+    /// Creates a new point in this space at (x, y, z).
+    pub const fn new(x: Scalar, y: Scalar, z: Scalar) -> Self {
+        Self::from_point(Point3::new(x, y, z))
+    }
+
+    /// Tags an untagged point as being in this space.
+    pub const fn from_point(point: Point3) -> Self {
+        Self { point, space: PhantomData }
+    }
+
+    /// Returns the untagged point.
+    pub const fn point(&self) -> Point3 {
+        self.point
+    }
+
+    /// Returns the distance between this point and the point 'rhs', which must be in the same space.
+    pub fn distance(&self, rhs: &Self) -> Scalar {
+        self.point.distance(&rhs.point)
+    }
+}
+
+impl<S> std::ops::Sub for Point3In<S> {
+    type Output = Vector3In<S>;
+
+    fn sub(self, rhs: Self) -> Vector3In<S> {
+        Vector3In::from_vector(self.point - rhs.point)
+    }
+}
+
+impl<S> std::ops::Add<Vector3In<S>> for Point3In<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector3In<S>) -> Self {
+        Self::from_point(self.point + rhs.vector)
+    }
+}
+
+impl<S> std::ops::Sub<Vector3In<S>> for Point3In<S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector3In<S>) -> Self {
+        Self::from_point(self.point - rhs.vector)
+    }
+}
+
+impl<Src, Dst> std::ops::Mul<Matrix4x4Between<Src, Dst>> for Point3In<Src> {
+    type Output = Point3In<Dst>;
+
+    fn mul(self, rhs: Matrix4x4Between<Src, Dst>) -> Point3In<Dst> {
+        Point3In::from_point(self.point * rhs.matrix)
+    }
+}
+
+/// A `Matrix4x4` that transforms from the space `Src` to the space `Dst`.
+///
+/// As transformations are applied in reading order, a `Matrix4x4Between<A, B>` multiplied by a
+/// `Matrix4x4Between<B, C>` gives a `Matrix4x4Between<A, C>`.
+#[repr(transparent)]
+pub struct Matrix4x4Between<Src, Dst> {
+    matrix: Matrix4x4,
+    spaces: PhantomData<fn(Src) -> Dst>,
+}
+impl_tagged_traits!(Matrix4x4Between<Src, Dst>, matrix);
+
+impl<Src, Dst> Matrix4x4Between<Src, Dst> {
+    /// Tags an untagged matrix as transforming between these spaces.
+    pub const fn new(matrix: Matrix4x4) -> Self {
+        Self { matrix, spaces: PhantomData }
+    }
+
+    /// Returns the untagged matrix.
+    pub const fn matrix(&self) -> Matrix4x4 {
+        self.matrix
+    }
+
+    /// Calculates the Inverse matrix, which transforms in the opposite direction. See `Matrix4x4::inverse`.
+    pub fn inverse(&self) -> Matrix4x4Between<Dst, Src> {
+        Matrix4x4Between::new(self.matrix.inverse())
+    }
+
+    /// Transforms a position from one space to the other. See `Matrix4x4::transform_point3`.
+    pub fn transform_point3(&self, point: &Point3In<Src>) -> Point3In<Dst> {
+        *point * *self
+    }
+
+    /// Transforms a direction from one space to the other. See `Matrix4x4::transform_vector3`.
+    pub fn transform_vector3(&self, vector: &Vector3In<Src>) -> Vector3In<Dst> {
+        Vector3In::from_vector(self.matrix.transform_vector3(&vector.vector))
+    }
+
+    /// Transforms a surface normal from one space to the other. See `Matrix4x4::transform_normal`.
+    pub fn transform_normal(&self, normal: &Vector3In<Src>) -> Vector3In<Dst> {
+        Vector3In::from_vector(self.matrix.transform_normal(&normal.vector))
+    }
+}
+
+impl<Src, Via, Dst> std::ops::Mul<Matrix4x4Between<Via, Dst>> for Matrix4x4Between<Src, Via> {
+    type Output = Matrix4x4Between<Src, Dst>;
+
+    fn mul(self, rhs: Matrix4x4Between<Via, Dst>) -> Matrix4x4Between<Src, Dst> {
+        Matrix4x4Between::new(self.matrix * rhs.matrix)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{AngleScalar, Degrees, Error, Scalar};
+use crate::matrix::{Affine2D, DAffine2D};
+use crate::vector::{Vector2, DVector2};
+
+macro_rules! define_transform2d {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector2: ident, $affine2d: ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $type {
+            pub translation: $vector2,
+            pub rotation: Degrees,
+            pub scale: $vector2,
+        }
+
+        impl Default for $type {
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " from a translation, rotation and scale.")]
+            pub const fn new(translation: $vector2, rotation: Degrees, scale: $vector2) -> Self {
+                Self { translation, rotation, scale }
+            }
+
+            /// Creates the transformation that leaves everything where it is.
+            pub const fn identity() -> Self {
+                Self {
+                    translation: $vector2::new(0.0, 0.0),
+                    rotation: Degrees(0.0),
+                    scale: $vector2::new(1.0, 1.0),
+                }
+            }
+
+            #[doc = concat!("Splits a `", stringify!($affine2d), "` into a translation, rotation and scale.")]
+            ///
+            /// A transformation containing skew cannot be represented, so the skew is discarded.
+            /// A reflection is kept as a negative Y scale.
+            /// The X scale is not checked, so a matrix that collapses the X axis results in NaN elements.
+            /// Use `try_from_matrix` if this is a possibility.
+            pub fn from_matrix(matrix: &$affine2d) -> Self {
+                let scale_x = (matrix.c00 * matrix.c00 + matrix.c10 * matrix.c10).sqrt();
+                let scale_y = matrix.determinant() / scale_x;
+                let rotation = <$scalar as AngleScalar>::degrees((-matrix.c10).atan2(matrix.c00));
+                Self {
+                    translation: matrix.translation(),
+                    rotation,
+                    scale: $vector2::new(scale_x, scale_y),
+                }
+            }
+
+            #[doc = concat!("Splits a `", stringify!($affine2d), "` into a translation, rotation and scale, unless it collapses the X axis.")]
+            ///
+            /// Unlike `from_matrix`, this returns `Error::SingularMatrix` rather than NaN elements
+            /// when the X scale is less than or equal to `tolerance`.
+            pub fn try_from_matrix(matrix: &$affine2d, tolerance: $scalar) -> Result<Self, Error> {
+                let scale_x = (matrix.c00 * matrix.c00 + matrix.c10 * matrix.c10).sqrt();
+                if scale_x.is_nan() || scale_x <= tolerance {
+                    return Err(Error::SingularMatrix);
+                }
+                Ok(Self::from_matrix(matrix))
+            }
+
+            /// Builds the matrix that scales, then rotates, then translates.
+            pub fn to_matrix(&self) -> $affine2d {
+                $affine2d::from_nonuniform_scale(&self.scale)
+                    * $affine2d::from_rotation(self.rotation)
+                    * $affine2d::from_translation(&self.translation)
+            }
+
+            /// Transforms a position, applying the scale, rotation and translation.
+            pub fn transform_point2(&self, point: &$vector2) -> $vector2 {
+                self.to_matrix().transform_point2(point)
+            }
+
+            /// Transforms a direction, applying the scale and rotation only.
+            pub fn transform_vector2(&self, vector: &$vector2) -> $vector2 {
+                self.to_matrix().transform_vector2(vector)
+            }
+        }
+
+        impl From<$type> for $affine2d {
+            fn from(transform: $type) -> Self {
+                transform.to_matrix()
+            }
+        }
+    };
+}
+
+define_transform2d!(
+    /// A 2D transformation stored as a separate translation, rotation and scale, which is convenient to edit
+    /// for sprites and UI elements. Convert it into an `Affine2D` to combine it with other transformations.
+    Transform2D, Scalar, Vector2, Affine2D
+);
+define_transform2d!(
+    /// A 2D transformation with double precision. See `Transform2D` for details.
+    DTransform2D, f64, DVector2, DAffine2D
+);
+
+impl Transform2D {
+    /// Converts to double precision. This conversion is lossless.
+    pub fn to_f64(&self) -> DTransform2D {
+        DTransform2D::new(self.translation.to_f64(), self.rotation, self.scale.to_f64())
+    }
+}
+
+impl DTransform2D {
+    /// Converts to single precision, rounding every element to the nearest `f32`.
+    pub fn to_f32(&self) -> Transform2D {
+        Transform2D::new(self.translation.to_f32(), self.rotation, self.scale.to_f32())
+    }
+}
+
+impl From<Transform2D> for DTransform2D {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_f64()
+    }
+}
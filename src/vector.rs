@@ -1,259 +1,480 @@
-use crate::Scalar;
-use crate::matrix::{Matrix3x3, Matrix4x4};
-use crate::quaternion::Quaternion;
-
-/// A 2-dimensional vector
-#[repr(C)]
-#[derive(Debug, Default, PartialEq, Copy, Clone)]
-pub struct Vector2 {
-    pub x: Scalar,
-    pub y: Scalar,
-}
+use crate::{Error, Scalar};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4, DMatrix2x2, DMatrix3x3, DMatrix4x4};
+use crate::quaternion::{Quaternion, DQuaternion};
+
+macro_rules! define_vector2 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $matrix2x2: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+        }
 
-impl Vector2 {
-    /// Creates a new instance of a Vector4 with values (x, y).
-    pub const fn new(x: Scalar, y: Scalar) -> Self {
-        Vector2 { x, y }
-    }
-
-    /// Returns a vector of (1.0, 0.0, 0.0).
-    pub const fn unit_x() -> Self {
-        Vector2 { x: 1.0, y: 0.0 }
-    }
-
-    /// Returns a vector of (0.0, 1.0).
-    pub const fn unit_y() -> Self {
-        Vector2 { x: 0.0, y: 1.0 }
-    }
-
-    /// Returns the dot product of the vector with the vector 'rhs'.
-    pub fn dot(&self, rhs: &Vector2) -> Scalar {
-        self.x * rhs.x + self.y * rhs.y
-    }
-
-    /// The length of the vector.
-    pub fn length(&mut self) -> Scalar {
-        (self.x + self.y).sqrt()
-    }
-
-    /// Normalizes the vector to a length of one.
-    pub fn normalize(&mut self) {
-        let len = self.length();
-        self.x /= len;
-        self.y /= len;
-    }
-}
-impl_add_self!(Vector2, x, y);
-impl_sub_self!(Vector2, x, y);
-impl_mul_scalar!(Vector2, x, y);
-impl_div_scalar!(Vector2, x, y);
-impl_negate_self!(Vector2, x, y);
-impl_approx!(Vector2, x, y);
-
-/// A 3-dimensional vector
-#[repr(C)]
-#[derive(Debug, Default, PartialEq, Copy, Clone)]
-pub struct Vector3 {
-    pub x: Scalar,
-    pub y: Scalar,
-    pub z: Scalar,
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " with values (x, y).")]
+            pub const fn new(x: $scalar, y: $scalar) -> Self {
+                $type { x, y }
+            }
+
+            /// Returns a vector of (1.0, 0.0).
+            pub const fn unit_x() -> Self {
+                $type { x: 1.0, y: 0.0 }
+            }
+
+            /// Returns a vector of (0.0, 1.0).
+            pub const fn unit_y() -> Self {
+                $type { x: 0.0, y: 1.0 }
+            }
+
+            /// Returns the dot product of the vector with the vector 'rhs'.
+            pub fn dot(&self, rhs: &$type) -> $scalar {
+                self.x * rhs.x + self.y * rhs.y
+            }
+
+            /// The length of the vector.
+            pub fn length(&self) -> $scalar {
+                (self.x * self.x + self.y * self.y).sqrt()
+            }
+
+            /// Normalizes the vector to a length of one.
+            /// The length is not checked, so a zero length vector results in NaN elements. Use `try_normalize` if this is a possibility.
+            pub fn normalize(&mut self) {
+                let len = self.length();
+                self.x /= len;
+                self.y /= len;
+            }
+
+            /// Normalizes the vector to a length of one, unless its length is less than or equal to `tolerance`.
+            ///
+            /// Unlike `normalize`, this returns `Error::ZeroLength` and leaves the vector unchanged rather than filling it with NaN.
+            pub fn try_normalize(&mut self, tolerance: $scalar) -> Result<(), Error> {
+                let len = self.length();
+                if len.is_nan() || len <= tolerance {
+                    return Err(Error::ZeroLength);
+                }
+                self.x /= len;
+                self.y /= len;
+                Ok(())
+            }
+
+            /// Returns a normalized copy of the vector, or `None` if its length is less than or equal to `tolerance`.
+            pub fn checked_normalize(&self, tolerance: $scalar) -> Option<$type> {
+                let mut result = *self;
+                result.try_normalize(tolerance).ok().map(|_| result)
+            }
+        }
+        impl_add_self!($type, x, y);
+        impl_sub_self!($type, x, y);
+        impl_mul_scalar!($type; $scalar; x, y);
+        impl_div_scalar!($type; $scalar; x, y);
+        impl_negate_self!($type, x, y);
+        impl_approx!($type; $scalar; x, y);
+
+        impl std::ops::Mul<$matrix2x2> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $matrix2x2) -> $type {
+                $type {
+                    x: self.x * rhs.c00 + self.y * rhs.c01,
+                    y: self.x * rhs.c10 + self.y * rhs.c11,
+                }
+            }
+        }
+
+        impl std::ops::MulAssign<$matrix2x2> for $type {
+            fn mul_assign(&mut self, rhs: $matrix2x2) {
+                *self = *self * rhs;
+            }
+        }
+    };
 }
 
-impl Vector3 {
-    /// Creates a new instance of a Vector3 with values (x, y, z).
-    pub const fn new(x: Scalar, y: Scalar, z: Scalar) -> Self {
-        Vector3 { x, y, z }
-    }
-
-    /// Returns a vector of (1.0, 0.0, 0.0).
-    pub const fn unit_x() -> Self {
-        Vector3 {
-            x: 1.0,
-            y: 0.0,
-            z: 0.0,
+macro_rules! define_vector3 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector4: ident, $matrix3x3: ident, $matrix4x4: ident, $quaternion: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+            pub z: $scalar,
         }
-    }
-
-    /// Returns a vector of (0.0, 1.0, 0.0).
-    pub const fn unit_y() -> Self {
-        Vector3 {
-            x: 0.0,
-            y: 1.0,
-            z: 0.0,
+
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " with values (x, y, z).")]
+            pub const fn new(x: $scalar, y: $scalar, z: $scalar) -> Self {
+                $type { x, y, z }
+            }
+
+            /// Returns a vector of (1.0, 0.0, 0.0).
+            pub const fn unit_x() -> Self {
+                $type {
+                    x: 1.0,
+                    y: 0.0,
+                    z: 0.0,
+                }
+            }
+
+            /// Returns a vector of (0.0, 1.0, 0.0).
+            pub const fn unit_y() -> Self {
+                $type {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0,
+                }
+            }
+
+            /// Returns a vector of (0.0, 0.0, 1.0).
+            pub const fn unit_z() -> Self {
+                $type {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 1.0,
+                }
+            }
+
+            /// Returns the dot product of the vector with the vector 'rhs'.
+            pub fn dot(&self, rhs: &$type) -> $scalar {
+                self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+            }
+
+            /// Returns the cross product of the vector with the vector 'rhs'.
+            pub fn cross(&self, rhs: &$type) -> $type {
+                $type {
+                    x: self.y * rhs.z - self.z * rhs.y,
+                    y: self.z * rhs.x - self.x * rhs.z,
+                    z: self.x * rhs.y - self.y * rhs.x,
+                }
+            }
+
+            /// Returns two unit vectors that are perpendicular to this vector and to each other, which is expected to be normalized.
+            ///
+            /// The three vectors form a right-handed basis, so `Matrix3x3::from_basis(&a, &b, self)` is a rotation.
+            /// This uses the branchless method of Duff et al., "Building an Orthonormal Basis, Revisited" (2017),
+            /// which has no singularity and stays accurate for every direction. The basis does jump when the vector
+            /// crosses the plane where Z is zero, so it is not suited to vectors that are animated smoothly.
+            pub fn orthonormal_basis(&self) -> ($type, $type) {
+                let sign = (1.0 as $scalar).copysign(self.z);
+                let a = -1.0 / (sign + self.z);
+                let b = self.x * self.y * a;
+                (
+                    $type::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+                    $type::new(b, sign + self.y * self.y * a, -self.y),
+                )
+            }
+
+            /// Returns a unit vector perpendicular to this vector, which does not need to be normalized.
+            ///
+            /// The length is not checked, so a zero length vector results in NaN elements.
+            pub fn any_orthogonal(&self) -> $type {
+                let mut normal = *self;
+                normal.normalize();
+                normal.orthonormal_basis().0
+            }
+
+            /// The length of the vector.
+            pub fn length(&self) -> $scalar {
+                (self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0)).sqrt()
+            }
+
+            /// Normalizes the vector to a length of one.
+            /// The length is not checked, so a zero length vector results in NaN elements. Use `try_normalize` if this is a possibility.
+            pub fn normalize(&mut self) {
+                let len = self.length();
+                self.x /= len;
+                self.y /= len;
+                self.z /= len;
+            }
+
+            /// Normalizes the vector to a length of one, unless its length is less than or equal to `tolerance`.
+            ///
+            /// Unlike `normalize`, this returns `Error::ZeroLength` and leaves the vector unchanged rather than filling it with NaN.
+            pub fn try_normalize(&mut self, tolerance: $scalar) -> Result<(), Error> {
+                let len = self.length();
+                if len.is_nan() || len <= tolerance {
+                    return Err(Error::ZeroLength);
+                }
+                self.x /= len;
+                self.y /= len;
+                self.z /= len;
+                Ok(())
+            }
+
+            /// Returns a normalized copy of the vector, or `None` if its length is less than or equal to `tolerance`.
+            pub fn checked_normalize(&self, tolerance: $scalar) -> Option<$type> {
+                let mut result = *self;
+                result.try_normalize(tolerance).ok().map(|_| result)
+            }
         }
-    }
-
-    /// Returns a vector of (0.0, 0.0, 1.0).
-    pub const fn unit_z() -> Self {
-        Vector3 {
-            x: 0.0,
-            y: 0.0,
-            z: 1.0,
+        impl_add_self!($type, x, y, z);
+        impl_sub_self!($type, x, y, z);
+        impl_mul_scalar!($type; $scalar; x, y, z);
+        impl_div_scalar!($type; $scalar; x, y, z);
+        impl_negate_self!($type, x, y, z);
+        impl_approx!($type; $scalar; x, y, z);
+
+        impl std::ops::Mul<$matrix3x3> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $matrix3x3) -> $type {
+                $type {
+                    x: self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02,
+                    y: self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12,
+                    z: self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22,
+                }
+            }
         }
-    }
-
-    /// Returns the dot product of the vector with the vector 'rhs'.
-    pub fn dot(&self, rhs: &Vector3) -> Scalar {
-        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
-    }
-
-    /// Returns the cross product of the vector with the vector 'rhs'.
-    pub fn cross(&self, rhs: &Vector3) -> Vector3 {
-        let mut result = Vector3::default();
-        result.x = self.y * rhs.z - self.z * rhs.y;
-        result.y = self.z * rhs.x - self.x * rhs.z;
-        result.z = self.x * rhs.y - self.y * rhs.x;
-        result
-    }
-
-    /// The length of the vector.
-    pub fn length(&mut self) -> Scalar {
-        (self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0)).sqrt()
-    }
-
-    /// Normalizes the vector to a length of one.
-    pub fn normalize(&mut self) {
-        let len = self.length();
-        self.x /= len;
-        self.y /= len;
-        self.z /= len;
-    }
-}
-impl_add_self!(Vector3, x, y, z);
-impl_sub_self!(Vector3, x, y, z);
-impl_mul_scalar!(Vector3, x, y, z);
-impl_div_scalar!(Vector3, x, y, z);
-impl_negate_self!(Vector3, x, y, z);
-impl_approx!(Vector3, x, y, z);
-
-impl std::ops::Mul<Matrix3x3> for Vector3 {
-    type Output = Vector3;
-
-    fn mul(self, rhs: Matrix3x3) -> Vector3 {
-        Vector3 {
-            x: self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02,
-            y: self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12,
-            z: self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22,
+
+        impl std::ops::MulAssign<$matrix3x3> for $type {
+            fn mul_assign(&mut self, rhs: $matrix3x3) {
+                let x = self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02;
+                let y = self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12;
+                let z = self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22;
+                self.x = x;
+                self.y = y;
+                self.z = z;
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign<Matrix3x3> for Vector3 {
-    fn mul_assign(&mut self, rhs: Matrix3x3) {
-        let x = self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02;
-        let y = self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12;
-        let z = self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22;
-        self.x = x;
-        self.y = y;
-        self.z = z;
-    }
-}
+        impl std::ops::Mul<$matrix4x4> for $type {
+            type Output = $type;
 
-impl std::ops::Mul<Matrix4x4> for Vector3 {
-    type Output = Vector3;
+            fn mul(self, rhs: $matrix4x4) -> $type {
+                rhs.transform_vector3(&self)
+            }
+        }
 
-    fn mul(self, rhs: Matrix4x4) -> Vector3 {
-        (Vector4::from(self) * rhs).into()
-    }
-}
+        impl std::ops::MulAssign<$matrix4x4> for $type {
+            fn mul_assign(&mut self, rhs: $matrix4x4) {
+                *self = rhs.transform_vector3(self);
+            }
+        }
 
-impl std::ops::MulAssign<Matrix4x4> for Vector3 {
-    fn mul_assign(&mut self, rhs: Matrix4x4) {
-        let result: Vector3 = (Vector4::from(*self) * rhs).into();
-        self.x = result.x;
-        self.y = result.y;
-        self.z = result.z;
-    }
-}
+        impl std::ops::Mul<$quaternion> for $type {
+            type Output = $type;
 
-impl std::ops::Mul<Quaternion> for Vector3 {
-    type Output = Vector3;
+            fn mul(self, rhs: $quaternion) -> $type {
+                ($vector4::from(self) * $matrix4x4::from(rhs)).into()
+            }
+        }
 
-    fn mul(self, rhs: Quaternion) -> Vector3 {
-        (Vector4::from(self) * Matrix4x4::from(rhs)).into()
-    }
-}
+        impl std::ops::MulAssign<$quaternion> for $type {
+            fn mul_assign(&mut self, rhs: $quaternion) {
+                let result: $type = ($vector4::from(*self) * $matrix4x4::from(rhs)).into();
+                self.x = result.x;
+                self.y = result.y;
+                self.z = result.z;
+            }
+        }
 
-impl std::ops::MulAssign<Quaternion> for Vector3 {
-    fn mul_assign(&mut self, rhs: Quaternion) {
-        let result: Vector3 = (Vector4::from(*self) * Matrix4x4::from(rhs)).into();
-        self.x = result.x;
-        self.y = result.y;
-        self.z = result.z;
-    }
+        impl From<$vector4> for $type {
+            fn from(vec4: $vector4) -> Self {
+                $type {
+                    x: vec4.x / vec4.w,
+                    y: vec4.y / vec4.w,
+                    z: vec4.z / vec4.w,
+                }
+            }
+        }
+    };
 }
 
-impl From<Vector4> for Vector3 {
-    fn from(vec4: Vector4) -> Self {
-        Vector3 {
-            x: vec4.x / vec4.w,
-            y: vec4.y / vec4.w,
-            z: vec4.z / vec4.w,
+macro_rules! define_vector4 {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $vector3: ident, $matrix4x4: ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $type {
+            pub x: $scalar,
+            pub y: $scalar,
+            pub z: $scalar,
+            pub w: $scalar,
         }
-    }
-}
 
-/// A 4-dimensional vector
-#[repr(C)]
-#[derive(Debug, Default, PartialEq, Copy, Clone)]
-pub struct Vector4 {
-    pub x: Scalar,
-    pub y: Scalar,
-    pub z: Scalar,
-    pub w: Scalar,
-}
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " with values (x, y, z, w).")]
+            pub const fn new(x: $scalar, y: $scalar, z: $scalar, w: $scalar) -> Self {
+                $type { x, y, z, w }
+            }
 
-impl Vector4 {
-    /// Creates a new instance of a Vector4 with values (x, y, z, w).
-    pub const fn new(x: Scalar, y: Scalar, z: Scalar, w: Scalar) -> Self {
-        Vector4 { x, y, z, w }
-    }
+            /// Returns the dot product of the vector with the vector 'rhs'.
+            pub fn dot(&self, rhs: &$type) -> $scalar {
+                self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+            }
+        }
+        impl_add_self!($type, x, y, z, w);
+        impl_sub_self!($type, x, y, z, w);
+        impl_mul_scalar!($type; $scalar; x, y, z, w);
+        impl_div_scalar!($type; $scalar; x, y, z, w);
+        impl_negate_self!($type, x, y, z, w);
+        impl_approx!($type; $scalar; x, y, z, w);
+
+        impl std::ops::Mul<$matrix4x4> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $matrix4x4) -> $type {
+                $type {
+                    x: self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02 + self.w * rhs.c03,
+                    y: self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12 + self.w * rhs.c13,
+                    z: self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22 + self.w * rhs.c23,
+                    w: self.x * rhs.c30 + self.y * rhs.c31 + self.z * rhs.c32 + self.w * rhs.c33,
+                }
+            }
+        }
 
-    /// Returns the dot product of the vector with the vector 'rhs'.
-    pub fn dot(&self, rhs: &Vector4) -> Scalar {
-        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
-    }
-}
-impl_add_self!(Vector4, x, y, z, w);
-impl_sub_self!(Vector4, x, y, z, w);
-impl_mul_scalar!(Vector4, x, y, z, w);
-impl_div_scalar!(Vector4, x, y, z, w);
-impl_negate_self!(Vector4, x, y, z, w);
-impl_approx!(Vector4, x, y, z, w);
-
-impl std::ops::Mul<Matrix4x4> for Vector4 {
-    type Output = Vector4;
-
-    fn mul(self, rhs: Matrix4x4) -> Vector4 {
-        Vector4 {
-            x: self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02 + self.w * rhs.c03,
-            y: self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12 + self.w * rhs.c13,
-            z: self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22 + self.w * rhs.c23,
-            w: self.x * rhs.c30 + self.y * rhs.c31 + self.z * rhs.c32 + self.w * rhs.c33,
+        impl std::ops::MulAssign<$matrix4x4> for $type {
+            fn mul_assign(&mut self, rhs: $matrix4x4) {
+                let x = self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02 + self.w * rhs.c03;
+                let y = self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12 + self.w * rhs.c13;
+                let z = self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22 + self.w * rhs.c23;
+                let w = self.x * rhs.c30 + self.y * rhs.c31 + self.z * rhs.c32 + self.w * rhs.c33;
+                self.x = x;
+                self.y = y;
+                self.z = z;
+                self.w = w;
+            }
         }
-    }
-}
 
-impl std::ops::MulAssign<Matrix4x4> for Vector4 {
-    fn mul_assign(&mut self, rhs: Matrix4x4) {
-        let x = self.x * rhs.c00 + self.y * rhs.c01 + self.z * rhs.c02 + self.w * rhs.c03;
-        let y = self.x * rhs.c10 + self.y * rhs.c11 + self.z * rhs.c12 + self.w * rhs.c13;
-        let z = self.x * rhs.c20 + self.y * rhs.c21 + self.z * rhs.c22 + self.w * rhs.c23;
-        let w = self.x * rhs.c30 + self.y * rhs.c31 + self.z * rhs.c32 + self.w * rhs.c33;
-        self.x = x;
-        self.y = y;
-        self.z = z;
-        self.w = w;
-    }
+        impl From<$vector3> for $type {
+            fn from(vec3: $vector3) -> Self {
+                $type {
+                    x: vec3.x,
+                    y: vec3.y,
+                    z: vec3.z,
+                    w: 1.0,
+                }
+            }
+        }
+    };
 }
 
-impl From<Vector3> for Vector4 {
-    fn from(vec3: Vector3) -> Self {
-        Vector4 {
-            x: vec3.x,
-            y: vec3.y,
-            z: vec3.z,
-            w: 1.0,
+define_vector2!(
+    /// A 2-dimensional vector
+    Vector2, Scalar, Matrix2x2
+);
+define_vector3!(
+    /// A 3-dimensional vector
+    ///
+    /// Multiplying a vector by a `Matrix4x4` treats it as a direction, so the translation is not applied.
+    /// To transform a position, use a `Point3`, or `Matrix4x4::transform_point3`.
+    Vector3, Scalar, Vector4, Matrix3x3, Matrix4x4, Quaternion
+);
+define_vector4!(
+    /// A 4-dimensional vector
+    Vector4, Scalar, Vector3, Matrix4x4
+);
+
+define_vector2!(
+    /// A 2-dimensional vector, with double precision
+    DVector2, f64, DMatrix2x2
+);
+define_vector3!(
+    /// A 3-dimensional vector, with double precision
+    DVector3, f64, DVector4, DMatrix3x3, DMatrix4x4, DQuaternion
+);
+define_vector4!(
+    /// A 4-dimensional vector, with double precision
+    DVector4, f64, DVector3, DMatrix4x4
+);
+
+impl_precision_conversion!(Vector2, DVector2, x, y);
+impl_precision_conversion!(Vector3, DVector3, x, y, z);
+impl_precision_conversion!(Vector4, DVector4, x, y, z, w);
+
+macro_rules! define_integer_vector {
+    ($(#[$attr:meta])* $type: ident, $scalar: ty, $float: ident, $($field:ident),+) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone)]
+        pub struct $type {
+            $(pub $field: $scalar,)+
         }
-    }
+
+        impl $type {
+            #[doc = concat!("Creates a new instance of a ", stringify!($type), " with values (", stringify!($($field),+), ").")]
+            pub const fn new($($field: $scalar),+) -> Self {
+                $type { $($field),+ }
+            }
+
+            /// Returns a vector containing the smallest of each element of this vector and 'rhs'.
+            pub fn min(&self, rhs: &$type) -> $type {
+                $type { $($field: self.$field.min(rhs.$field)),+ }
+            }
+
+            /// Returns a vector containing the largest of each element of this vector and 'rhs'.
+            pub fn max(&self, rhs: &$type) -> $type {
+                $type { $($field: self.$field.max(rhs.$field)),+ }
+            }
+
+            /// Returns the Manhattan (taxicab) distance to 'rhs', which is the sum of the distances along each axis.
+            /// The distance along a single axis can already reach `u32::MAX`, so the sum is returned as a `u64`, which cannot overflow.
+            pub fn manhattan_distance(&self, rhs: &$type) -> u64 {
+                0 $(+ u64::from(self.$field.abs_diff(rhs.$field)))+
+            }
+
+            /// Returns the Chebyshev (chessboard) distance to 'rhs', which is the largest of the distances along each axis.
+            pub fn chebyshev_distance(&self, rhs: &$type) -> u32 {
+                0 $(.max(self.$field.abs_diff(rhs.$field)))+
+            }
+
+            /// Creates a vector by rounding each element down to the nearest integer.
+            /// Elements outside the range of the integer type are clamped to it, and NaN becomes zero.
+            pub fn from_floor(vector: &$float) -> Self {
+                $type { $($field: vector.$field.floor() as $scalar),+ }
+            }
+
+            /// Creates a vector by rounding each element to the nearest integer, with halfway cases rounded away from zero.
+            /// Elements outside the range of the integer type are clamped to it, and NaN becomes zero.
+            pub fn from_round(vector: &$float) -> Self {
+                $type { $($field: vector.$field.round() as $scalar),+ }
+            }
+
+            /// Creates a vector by dropping the fractional part of each element.
+            /// Elements outside the range of the integer type are clamped to it, and NaN becomes zero.
+            pub fn from_truncate(vector: &$float) -> Self {
+                $type { $($field: vector.$field as $scalar),+ }
+            }
+        }
+        impl_add_self!($type, $($field),+);
+        impl_sub_self!($type, $($field),+);
+        impl_mul_scalar!($type; $scalar; $($field),+);
+        impl_div_scalar!($type; $scalar; $($field),+);
+
+        impl From<$type> for $float {
+            fn from(vector: $type) -> Self {
+                $float { $($field: vector.$field as Scalar),+ }
+            }
+        }
+    };
 }
+
+define_integer_vector!(
+    /// A 2-dimensional vector of signed integers
+    IVector2, i32, Vector2, x, y
+);
+define_integer_vector!(
+    /// A 3-dimensional vector of signed integers
+    IVector3, i32, Vector3, x, y, z
+);
+define_integer_vector!(
+    /// A 4-dimensional vector of signed integers
+    IVector4, i32, Vector4, x, y, z, w
+);
+impl_negate_self!(IVector2, x, y);
+impl_negate_self!(IVector3, x, y, z);
+impl_negate_self!(IVector4, x, y, z, w);
+
+define_integer_vector!(
+    /// A 2-dimensional vector of unsigned integers
+    UVector2, u32, Vector2, x, y
+);
+define_integer_vector!(
+    /// A 3-dimensional vector of unsigned integers
+    UVector3, u32, Vector3, x, y, z
+);
+define_integer_vector!(
+    /// A 4-dimensional vector of unsigned integers
+    UVector4, u32, Vector4, x, y, z, w
+);
@@ -0,0 +1,72 @@
+#![cfg(feature = "cgmath")]
+
+#[macro_use]
+extern crate approx;
+
+use cgmath::{Rotation, Rotation3, SquareMatrix, Transform};
+use ezcgmath::prelude::*;
+use ezcgmath::matrix::{Matrix2x2, Matrix3x3};
+use ezcgmath::vector::{Vector2, Vector4};
+
+const M: Matrix4x4 = Matrix4x4 {
+    c00: 2.0, c10: 0.5, c20: 1.0, c30: 0.0,
+    c01: 1.0, c11: 3.0, c21: 0.0, c31: 0.0,
+    c02: 0.0, c12: 1.0, c22: 4.0, c32: 0.0,
+    c03: 5.0, c13: 6.0, c23: 7.0, c33: 1.0,
+};
+
+#[test]
+fn round_trips() {
+    assert_eq!(Vector2::from(cgmath::Vector2::from(Vector2::new(1.0, 2.0))), Vector2::new(1.0, 2.0));
+    assert_eq!(Vector3::from(cgmath::Vector3::from(Vector3::new(1.0, 2.0, 3.0))), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector4::from(cgmath::Vector4::from(Vector4::new(1.0, 2.0, 3.0, 4.0))), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    let rotation = Quaternion::from_euler(Degrees(10.0), Degrees(20.0), Degrees(30.0));
+    assert_eq!(Quaternion::from(cgmath::Quaternion::from(rotation)), rotation);
+    let m2 = Matrix2x2 { c00: 1.0, c10: 2.0, c01: 3.0, c11: 4.0 };
+    assert_eq!(Matrix2x2::from(cgmath::Matrix2::from(m2)), m2);
+    let m3 = Matrix3x3 {
+        c00: 1.0, c10: 2.0, c20: 3.0,
+        c01: 4.0, c11: 5.0, c21: 6.0,
+        c02: 7.0, c12: 8.0, c22: 9.0,
+    };
+    assert_eq!(Matrix3x3::from(cgmath::Matrix3::from(m3)), m3);
+    assert_eq!(Matrix4x4::from(cgmath::Matrix4::from(M)), M);
+}
+
+#[test]
+fn transform_point() {
+    let point = Vector3::new(1.0, -2.0, 3.0);
+    let expected = cgmath::Matrix4::from(M).transform_point(cgmath::Point3::new(1.0, -2.0, 3.0));
+    assert_relative_eq!(M.transform_point3(&point), Vector3::new(expected.x, expected.y, expected.z), epsilon = 1e-5);
+
+    let translation = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(cgmath::Matrix4::from(translation), cgmath::Matrix4::from_translation(cgmath::Vector3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn inverse() {
+    let expected = Matrix4x4::from(cgmath::Matrix4::from(M).invert().unwrap());
+    assert_relative_eq!(M.inverse(), expected, epsilon = 1e-5);
+}
+
+#[test]
+fn quaternion_rotation() {
+    let rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let expected = cgmath::Quaternion::from(rotation).rotate_vector(vector.into());
+    assert_relative_eq!(vector * rotation, Vector3::from(expected), epsilon = 1e-5);
+
+    let expected = Matrix4x4::from(cgmath::Matrix4::from(cgmath::Quaternion::from(rotation)));
+    assert_relative_eq!(Matrix4x4::from(rotation), expected, epsilon = 1e-6);
+}
+
+#[test]
+fn from_euler() {
+    let (x, y, z) = (Degrees(15.0), Degrees(-40.0), Degrees(75.0));
+    let euler = cgmath::Quaternion::from_angle_z(cgmath::Deg(z.0))
+        * cgmath::Quaternion::from_angle_y(cgmath::Deg(y.0))
+        * cgmath::Quaternion::from_angle_x(cgmath::Deg(x.0));
+    // ezcgmath applies rotations to row vectors, so its euler rotation is the inverse of the column-vector one.
+    let expected = Quaternion::from(euler.invert());
+    assert_relative_eq!(Quaternion::from_euler(x, y, z), expected, epsilon = 1e-6);
+}
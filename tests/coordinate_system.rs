@@ -0,0 +1,157 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::prelude::*;
+use ezcgmath::coordinate_system::*;
+use ezcgmath::Error;
+
+fn all_coordinate_systems() -> Vec<CoordinateSystem> {
+    let mut result = Vec::new();
+    for handedness in [Handedness::Left, Handedness::Right].iter() {
+        for up in Axis::ALL.iter() {
+            for forward in Axis::ALL.iter() {
+                if let Ok(system) = CoordinateSystem::new(*handedness, *up, *forward) {
+                    result.push(system);
+                }
+            }
+        }
+    }
+    result
+}
+
+fn test_transform() -> (Vector3, Quaternion, Vector3) {
+    let mut axis = Vector3::new(1.0, 2.0, -3.0);
+    axis.normalize();
+    (Vector3::new(4.0, -5.0, 6.0), Quaternion::from_axis_angle(&axis, Degrees(37.0)), Vector3::new(2.0, 3.0, 0.5))
+}
+
+fn compose(translation: &Vector3, rotation: &Quaternion, scale: &Vector3) -> Matrix4x4 {
+    Matrix4x4::from_nonuniform_scale(scale) * *rotation * Matrix4x4::from_translation(translation)
+}
+
+#[test]
+fn new() {
+    assert_eq!(all_coordinate_systems().len(), 48);
+    assert_eq!(CoordinateSystem::new(Handedness::Left, Axis::PositiveY, Axis::NegativeY), Err(Error::ParallelAxes));
+    assert_eq!(CoordinateSystem::new(Handedness::Left, Axis::PositiveY, Axis::PositiveZ), Ok(CoordinateSystem::EZCGMATH));
+}
+
+#[test]
+fn right() {
+    assert_eq!(CoordinateSystem::EZCGMATH.right(), Axis::PositiveX);
+    assert_eq!(CoordinateSystem::GLTF.right(), Axis::NegativeX);
+    assert_eq!(CoordinateSystem::BLENDER.right(), Axis::NegativeX);
+    for system in all_coordinate_systems() {
+        let right = system.right().to_vector3();
+        assert_eq!(right.dot(&system.up().to_vector3()), 0.0);
+        assert_eq!(right.dot(&system.forward().to_vector3()), 0.0);
+    }
+}
+
+#[test]
+fn blender_to_ezcgmath() {
+    let conversion = CoordinateSystem::BLENDER.conversion_to(&CoordinateSystem::EZCGMATH);
+    assert!(conversion.changes_handedness());
+    assert_eq!(conversion.vector3(&Vector3::new(1.0, 2.0, 3.0)), Vector3::new(-1.0, 3.0, -2.0));
+    assert_eq!(conversion.scale(&Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 3.0, 2.0));
+}
+
+#[test]
+fn gltf_to_ezcgmath() {
+    let conversion = CoordinateSystem::GLTF.conversion_to(&CoordinateSystem::EZCGMATH);
+    assert!(conversion.changes_handedness());
+    assert_eq!(conversion.vector3(&Vector3::new(1.0, 2.0, 3.0)), Vector3::new(-1.0, 2.0, 3.0));
+}
+
+#[test]
+fn blender_to_gltf() {
+    let conversion = CoordinateSystem::BLENDER.conversion_to(&CoordinateSystem::GLTF);
+    assert!(!conversion.changes_handedness());
+    assert_eq!(conversion.vector3(&Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 3.0, -2.0));
+}
+
+#[test]
+fn axes_keep_their_meaning() {
+    for source in all_coordinate_systems() {
+        for target in all_coordinate_systems() {
+            let conversion = source.conversion_to(&target);
+            assert_eq!(conversion.vector3(&source.right().to_vector3()), target.right().to_vector3());
+            assert_eq!(conversion.vector3(&source.up().to_vector3()), target.up().to_vector3());
+            assert_eq!(conversion.vector3(&source.forward().to_vector3()), target.forward().to_vector3());
+            assert_eq!(conversion.changes_handedness(), source.handedness() != target.handedness());
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    let (translation, rotation, scale) = test_transform();
+    let matrix = compose(&translation, &rotation, &scale);
+    let point = Point3::new(-1.0, 0.5, 2.0);
+    for source in all_coordinate_systems() {
+        for target in all_coordinate_systems() {
+            let there = source.conversion_to(&target);
+            let back = target.conversion_to(&source);
+            assert_eq!(back, there.inverse());
+            assert_eq!(back.vector3(&there.vector3(&translation)), translation);
+            assert_eq!(back.point3(&there.point3(&point)), point);
+            assert_eq!(back.scale(&there.scale(&scale)), scale);
+            assert_eq!(back.quaternion(&there.quaternion(&rotation)), rotation);
+            assert_eq!(back.matrix4x4(&there.matrix4x4(&matrix)), matrix);
+        }
+    }
+}
+
+#[test]
+fn conversions_are_consistent() {
+    let (translation, rotation, scale) = test_transform();
+    let matrix = compose(&translation, &rotation, &scale);
+    let point = Point3::new(-1.0, 0.5, 2.0);
+    let direction = Vector3::new(0.25, -2.0, 1.0);
+    for source in all_coordinate_systems() {
+        for target in all_coordinate_systems() {
+            let conversion = source.conversion_to(&target);
+            let converted_matrix = conversion.matrix4x4(&matrix);
+            assert_relative_eq!(conversion.point3(&(point * matrix)), conversion.point3(&point) * converted_matrix, epsilon = 1e-5);
+            assert_relative_eq!(conversion.vector3(&(direction * rotation)), conversion.vector3(&direction) * conversion.quaternion(&rotation), epsilon = 1e-5);
+            assert_relative_eq!(Matrix4x4::from(conversion.quaternion(&rotation)), conversion.matrix4x4(&Matrix4x4::from(rotation)), epsilon = 1e-6);
+
+            let (t, r, s) = conversion.translation_rotation_scale(&translation, &rotation, &scale);
+            assert_relative_eq!(compose(&t, &r, &s), converted_matrix, epsilon = 1e-5);
+        }
+    }
+}
+
+#[test]
+fn mirrored_scale() {
+    let scale = Vector3::new(-1.0, 2.0, 3.0);
+    assert_eq!(CoordinateSystem::EZCGMATH.conversion_to(&CoordinateSystem::EZCGMATH).scale(&scale), scale);
+    let conversion = CoordinateSystem::BLENDER.conversion_to(&CoordinateSystem::EZCGMATH);
+    assert_eq!(conversion.scale(&scale), Vector3::new(-1.0, 3.0, 2.0));
+
+    let (translation, rotation, _) = test_transform();
+    let scale = Vector3::new(2.0, -3.0, 0.5);
+    let matrix = compose(&translation, &rotation, &scale);
+    for source in all_coordinate_systems() {
+        for target in all_coordinate_systems() {
+            let conversion = source.conversion_to(&target);
+            let (t, r, s) = conversion.translation_rotation_scale(&translation, &rotation, &scale);
+            assert_eq!(s.x * s.y * s.z, scale.x * scale.y * scale.z);
+            assert_relative_eq!(compose(&t, &r, &s), conversion.matrix4x4(&matrix), epsilon = 1e-5);
+            assert_eq!(target.conversion_to(&source).scale(&s), scale);
+        }
+    }
+}
+
+#[test]
+fn composition() {
+    let systems = all_coordinate_systems();
+    for a in systems.iter() {
+        for b in systems.iter().step_by(5) {
+            for c in systems.iter().step_by(7) {
+                let via = a.conversion_to(b).matrix() * b.conversion_to(c).matrix();
+                assert_eq!(via, a.conversion_to(c).matrix());
+            }
+        }
+    }
+}
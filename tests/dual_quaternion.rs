@@ -0,0 +1,155 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::Degrees;
+use ezcgmath::dual_quaternion::{DDualQuaternion, DualQuaternion};
+use ezcgmath::matrix::Matrix4x4;
+use ezcgmath::quaternion::Quaternion;
+use ezcgmath::vector::Vector3;
+
+fn a() -> DualQuaternion {
+    let rotation = Quaternion::from_euler(Degrees(30.0), Degrees(-45.0), Degrees(10.0));
+    DualQuaternion::from_rotation_translation(&rotation, &Vector3::new(1.0, 2.0, -3.0))
+}
+
+fn b() -> DualQuaternion {
+    let rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(120.0));
+    DualQuaternion::from_rotation_translation(&rotation, &Vector3::new(-4.0, 0.5, 2.0))
+}
+
+fn matrix(transformation: &DualQuaternion) -> Matrix4x4 {
+    Matrix4x4::from(transformation.rotation()) * Matrix4x4::from_translation(&transformation.translation())
+}
+
+#[test]
+fn identity() {
+    let point = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(DualQuaternion::default(), DualQuaternion::identity());
+    assert_eq!(DualQuaternion::identity().transform_point3(&point), point);
+    assert_ulps_eq!(Matrix4x4::from(DualQuaternion::identity()), Matrix4x4::identity());
+}
+
+#[test]
+fn from_rotation_translation() {
+    let rotation = Quaternion::from_euler(Degrees(30.0), Degrees(-45.0), Degrees(10.0));
+    let translation = Vector3::new(1.0, 2.0, -3.0);
+    let transformation = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    assert_eq!(transformation.rotation(), rotation);
+    assert_relative_eq!(transformation.translation(), translation, epsilon = 1e-6);
+
+    let expected = Matrix4x4::from(rotation) * Matrix4x4::from_translation(&translation);
+    assert_relative_eq!(Matrix4x4::from(transformation), expected, epsilon = 1e-6);
+    let point = Vector3::new(-2.0, 0.5, 4.0);
+    assert_relative_eq!(transformation.transform_point3(&point), expected.transform_point3(&point), epsilon = 1e-5);
+    assert_relative_eq!(transformation.transform_vector3(&point), expected.transform_vector3(&point), epsilon = 1e-5);
+
+    assert_relative_eq!(DualQuaternion::from_translation(&translation).transform_point3(&point), point + translation);
+    assert_relative_eq!(DualQuaternion::from_rotation(&rotation).transform_point3(&point), point * rotation);
+}
+
+#[test]
+fn from_matrix() {
+    let expected = a();
+    assert_relative_eq!(DualQuaternion::from_matrix(&matrix(&expected)), expected, epsilon = 1e-6);
+    // Scale cannot be represented, so only the rotation and translation are kept.
+    let scaled = Matrix4x4::from_scale(2.0) * matrix(&expected);
+    let from_scaled = DualQuaternion::from_matrix(&scaled);
+    assert_relative_eq!(from_scaled.rotation(), expected.rotation(), epsilon = 1e-6);
+    assert_relative_eq!(from_scaled.translation(), expected.translation(), epsilon = 1e-5);
+}
+
+#[test]
+fn multiply() {
+    let point = Vector3::new(-2.0, 0.5, 4.0);
+    let combined = a() * b();
+    assert_relative_eq!(combined.transform_point3(&point), b().transform_point3(&a().transform_point3(&point)), epsilon = 1e-5);
+    assert_relative_eq!(Matrix4x4::from(combined), matrix(&a()) * matrix(&b()), epsilon = 1e-5);
+
+    let mut assigned = a();
+    assigned *= b();
+    assert_eq!(assigned, combined);
+}
+
+#[test]
+fn inverse() {
+    let point = Vector3::new(-2.0, 0.5, 4.0);
+    assert_relative_eq!(a() * a().inverse(), DualQuaternion::identity(), epsilon = 1e-6);
+    assert_relative_eq!(a().inverse() * a(), DualQuaternion::identity(), epsilon = 1e-6);
+    assert_relative_eq!(a().inverse().transform_point3(&a().transform_point3(&point)), point, epsilon = 1e-5);
+    assert_relative_eq!(Matrix4x4::from(a().inverse()), matrix(&a()).inverse(), epsilon = 1e-5);
+}
+
+#[test]
+fn normalize() {
+    let mut scaled = a();
+    scaled.real = Quaternion { x: scaled.real.x * 3.0, y: scaled.real.y * 3.0, z: scaled.real.z * 3.0, w: scaled.real.w * 3.0 };
+    scaled.dual = Quaternion { x: scaled.dual.x * 3.0, y: scaled.dual.y * 3.0, z: scaled.dual.z * 3.0, w: scaled.dual.w * 3.0 };
+    assert_relative_eq!(scaled.checked_normalize(1e-6).unwrap(), a(), epsilon = 1e-6);
+
+    let mut zero = DualQuaternion { real: Quaternion::default(), dual: Quaternion::default() };
+    assert_eq!(zero.try_normalize(1e-6), Err(ezcgmath::Error::ZeroLength));
+    assert_eq!(zero.real, Quaternion::default());
+}
+
+#[test]
+fn sclerp() {
+    assert_relative_eq!(a().sclerp(&b(), 0.0), a(), epsilon = 1e-6);
+    assert_relative_eq!(a().sclerp(&b(), 1.0), b(), epsilon = 1e-5);
+
+    let translation = DualQuaternion::from_translation(&Vector3::new(4.0, 0.0, -2.0));
+    let halfway = DualQuaternion::identity().sclerp(&translation, 0.5);
+    assert_relative_eq!(halfway, DualQuaternion::from_translation(&Vector3::new(2.0, 0.0, -1.0)), epsilon = 1e-6);
+
+    // A rotation around a vertical axis through (1, 0, 1), combined with a movement up that axis, is a screw motion.
+    let around_axis = |angle: f32, height: f32| {
+        let axis_position = Vector3::new(1.0, 0.0, 1.0);
+        DualQuaternion::from_translation(&-axis_position)
+            * DualQuaternion::from_rotation(&Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(angle)))
+            * DualQuaternion::from_translation(&(axis_position + Vector3::new(0.0, height, 0.0)))
+    };
+    let start = around_axis(0.0, 0.0);
+    let end = around_axis(90.0, 3.0);
+    assert_relative_eq!(start.sclerp(&end, 0.5), around_axis(45.0, 1.5), epsilon = 1e-5);
+    assert_relative_eq!(start.sclerp(&end, 0.25), around_axis(22.5, 0.75), epsilon = 1e-5);
+
+    // The negated dual quaternion is the same transformation, and must interpolate the same way.
+    let negated_end = DualQuaternion {
+        real: Quaternion { x: -end.real.x, y: -end.real.y, z: -end.real.z, w: -end.real.w },
+        dual: Quaternion { x: -end.dual.x, y: -end.dual.y, z: -end.dual.z, w: -end.dual.w },
+    };
+    assert_relative_eq!(start.sclerp(&negated_end, 0.5), around_axis(45.0, 1.5), epsilon = 1e-5);
+}
+
+#[test]
+fn weighted_blend() {
+    assert_relative_eq!(DualQuaternion::weighted_blend(&[(a(), 0.7)]), a(), epsilon = 1e-6);
+
+    let start = DualQuaternion::from_translation(&Vector3::new(2.0, 0.0, 0.0));
+    let end = DualQuaternion::from_translation(&Vector3::new(4.0, 2.0, 0.0));
+    let blended = DualQuaternion::weighted_blend(&[(start, 0.25), (end, 0.75)]);
+    assert_relative_eq!(blended, DualQuaternion::from_translation(&Vector3::new(3.5, 1.5, 0.0)), epsilon = 1e-6);
+
+    // Blending a twist keeps the length of the arm, where blending matrices would shrink it.
+    let twisted = DualQuaternion::from_rotation(&Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(170.0)));
+    let blended = DualQuaternion::weighted_blend(&[(DualQuaternion::identity(), 0.5), (twisted, 0.5)]);
+    let point = Vector3::new(0.0, 1.0, 0.0);
+    assert_relative_eq!(blended.transform_point3(&point).length(), 1.0, epsilon = 1e-5);
+    assert_relative_eq!(blended.rotation(), Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(85.0)), epsilon = 1e-6);
+
+    let normalized = DualQuaternion::weighted_blend(&[(a(), 0.3), (b(), 0.5)]);
+    assert_relative_eq!(normalized.real.dot(&normalized.real), 1.0, epsilon = 1e-6);
+    assert_relative_eq!(normalized.real.dot(&normalized.dual), 0.0, epsilon = 1e-6);
+
+    assert_eq!(DualQuaternion::weighted_blend(&[]), DualQuaternion::identity());
+    assert_eq!(DualQuaternion::weighted_blend(&[(a(), 0.0)]), DualQuaternion::identity());
+    assert_eq!(DualQuaternion::weighted_blend(&[(a(), -1.0)]), DualQuaternion::identity());
+    assert_eq!(DualQuaternion::weighted_blend(&[(a(), 1.0), (b(), -1.0)]), DualQuaternion::identity());
+}
+
+#[test]
+fn double_precision() {
+    let double = a().to_f64();
+    assert_relative_eq!(double.to_f32(), a());
+    assert_eq!(DDualQuaternion::from(a()), double);
+    assert_relative_eq!(double.translation().to_f32(), a().translation(), epsilon = 1e-6);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "glam")]
+
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::prelude::*;
+use ezcgmath::matrix::{Matrix2x2, Matrix3x3};
+use ezcgmath::vector::{Vector2, Vector4};
+
+const M: Matrix4x4 = Matrix4x4 {
+    c00: 2.0, c10: 0.5, c20: 1.0, c30: 0.0,
+    c01: 1.0, c11: 3.0, c21: 0.0, c31: 0.0,
+    c02: 0.0, c12: 1.0, c22: 4.0, c32: 0.0,
+    c03: 5.0, c13: 6.0, c23: 7.0, c33: 1.0,
+};
+
+#[test]
+fn round_trips() {
+    assert_eq!(Vector2::from(glam::Vec2::from(Vector2::new(1.0, 2.0))), Vector2::new(1.0, 2.0));
+    assert_eq!(Vector3::from(glam::Vec3::from(Vector3::new(1.0, 2.0, 3.0))), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector4::from(glam::Vec4::from(Vector4::new(1.0, 2.0, 3.0, 4.0))), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    let rotation = Quaternion::from_euler(Degrees(10.0), Degrees(20.0), Degrees(30.0));
+    assert_eq!(Quaternion::from(glam::Quat::from(rotation)), rotation);
+    let m2 = Matrix2x2 { c00: 1.0, c10: 2.0, c01: 3.0, c11: 4.0 };
+    assert_eq!(Matrix2x2::from(glam::Mat2::from(m2)), m2);
+    let m3 = Matrix3x3 {
+        c00: 1.0, c10: 2.0, c20: 3.0,
+        c01: 4.0, c11: 5.0, c21: 6.0,
+        c02: 7.0, c12: 8.0, c22: 9.0,
+    };
+    assert_eq!(Matrix3x3::from(glam::Mat3::from(m3)), m3);
+    assert_eq!(Matrix4x4::from(glam::Mat4::from(M)), M);
+}
+
+#[test]
+fn transform_point() {
+    let point = Vector3::new(1.0, -2.0, 3.0);
+    let expected = glam::Mat4::from(M).transform_point3(point.into());
+    assert_relative_eq!(M.transform_point3(&point), Vector3::from(expected), epsilon = 1e-5);
+
+    let translation = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(glam::Mat4::from(translation), glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn matrix_multiplication_order() {
+    let scale = Matrix4x4::from_nonuniform_scale(&Vector3::new(2.0, 1.0, 1.0));
+    let translation = Matrix4x4::from_translation(&Vector3::new(0.0, 0.0, -10.0));
+    let expected = glam::Mat4::from(translation) * glam::Mat4::from(scale);
+    assert_eq!(glam::Mat4::from(scale * translation), expected);
+}
+
+#[test]
+fn inverse() {
+    let expected = Matrix4x4::from(glam::Mat4::from(M).inverse());
+    assert_relative_eq!(M.inverse(), expected, epsilon = 1e-5);
+}
+
+#[test]
+fn quaternion_rotation() {
+    let rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let expected = glam::Quat::from(rotation) * glam::Vec3::from(vector);
+    assert_relative_eq!(vector * rotation, Vector3::from(expected), epsilon = 1e-5);
+
+    let expected = Matrix4x4::from(glam::Mat4::from_quat(rotation.into()));
+    assert_relative_eq!(Matrix4x4::from(rotation), expected, epsilon = 1e-6);
+}
+
+#[test]
+fn quaternion_multiplication_order() {
+    let a = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(30.0));
+    let b = Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(60.0));
+    let expected = Quaternion::from(glam::Quat::from(b) * glam::Quat::from(a));
+    assert_relative_eq!(a * b, expected, epsilon = 1e-6);
+}
+
+#[test]
+fn from_euler() {
+    let (x, y, z) = (Degrees(15.0), Degrees(-40.0), Degrees(75.0));
+    let euler = glam::Quat::from_euler(
+        glam::EulerRot::ZYX,
+        Radians::from(z).0,
+        Radians::from(y).0,
+        Radians::from(x).0,
+    );
+    // ezcgmath applies rotations to row vectors, so its euler rotation is the inverse of the column-vector one.
+    let expected = Quaternion::from(euler.inverse());
+    assert_relative_eq!(Quaternion::from_euler(x, y, z), expected, epsilon = 1e-6);
+}
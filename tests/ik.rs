@@ -0,0 +1,208 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::Degrees;
+use ezcgmath::ik::*;
+use ezcgmath::quaternion::Quaternion;
+use ezcgmath::vector::Vector3;
+
+/// A chain standing straight up from the origin, with bones of length 1 and every joint pointing its Z axis up the chain.
+fn chain(joints: usize) -> (Vec<Vector3>, Vec<Quaternion>) {
+    let positions = (0..joints).map(|i| Vector3::new(0.0, i as f32, 0.0)).collect();
+    let rotations = vec![Quaternion::from_rotation_arc(&Vector3::unit_z(), &Vector3::unit_y()); joints];
+    (positions, rotations)
+}
+
+fn direction(from: Vector3, to: Vector3) -> Vector3 {
+    let mut result = to - from;
+    result.normalize();
+    result
+}
+
+fn angle_between(a: Vector3, b: Vector3) -> f32 {
+    a.dot(&b).clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Checks that the bones kept their lengths, and that every rotation still points its Z axis along its bone.
+fn assert_rigid(positions: &[Vector3], rotations: &[Quaternion]) {
+    for i in 0..positions.len() - 1 {
+        assert_relative_eq!((positions[i + 1] - positions[i]).length(), 1.0, epsilon = 1e-4);
+        assert_relative_eq!(Vector3::unit_z() * rotations[i], direction(positions[i], positions[i + 1]), epsilon = 1e-4);
+    }
+    let last = positions.len() - 1;
+    assert_relative_eq!(Vector3::unit_z() * rotations[last], direction(positions[last - 1], positions[last]), epsilon = 1e-4);
+}
+
+mod two_bone {
+    use super::*;
+
+    fn leg() -> ([Vector3; 3], [Quaternion; 3]) {
+        let positions = [Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 0.0)];
+        let down = Quaternion::from_rotation_arc(&Vector3::unit_z(), &-Vector3::unit_y());
+        (positions, [down; 3])
+    }
+
+    #[test]
+    fn reach() {
+        let (mut positions, mut rotations) = leg();
+        let target = Vector3::new(0.3, 0.6, 0.2);
+        let pole = Vector3::new(0.0, 1.0, 5.0);
+        let distance = solve_two_bone(&mut positions, &mut rotations, &target, &pole, &JointLimit::Free);
+        assert_relative_eq!(distance, 0.0, epsilon = 1e-5);
+        assert_relative_eq!(positions[2], target, epsilon = 1e-5);
+        assert_eq!(positions[0], Vector3::new(0.0, 2.0, 0.0));
+
+        // The knee bends towards the pole, in the plane through the hip, the target and the pole.
+        let normal = (target - positions[0]).cross(&(pole - positions[0]));
+        assert_relative_eq!((positions[1] - positions[0]).dot(&normal), 0.0, epsilon = 1e-5);
+        assert!(positions[1].z > 0.0);
+
+        for i in 0..2 {
+            assert_relative_eq!((positions[i + 1] - positions[i]).length(), 1.0, epsilon = 1e-5);
+            assert_relative_eq!(Vector3::unit_z() * rotations[i], direction(positions[i], positions[i + 1]), epsilon = 1e-5);
+        }
+        assert_eq!(rotations[2], rotations[1]);
+        // The rotations keep the knee hinge perpendicular to the bend plane.
+        let mut hinge = normal;
+        hinge.normalize();
+        let local_hinge = Vector3::unit_x();
+        assert_relative_eq!((local_hinge * rotations[0]).cross(&hinge).length(), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn out_of_reach() {
+        let (mut positions, mut rotations) = leg();
+        let target = Vector3::new(3.0, 2.0, 0.0);
+        let distance = solve_two_bone(&mut positions, &mut rotations, &target, &Vector3::new(0.0, 2.0, 5.0), &JointLimit::Free);
+        assert_relative_eq!(distance, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(positions[1], Vector3::new(1.0, 2.0, 0.0), epsilon = 1e-5);
+        assert_relative_eq!(positions[2], Vector3::new(2.0, 2.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn limits() {
+        let pole = Vector3::new(0.0, 1.0, 5.0);
+        let bend = |positions: &[Vector3; 3]| angle_between(positions[1] - positions[0], positions[2] - positions[1]);
+
+        let (mut positions, mut rotations) = leg();
+        let limit = JointLimit::Hinge { axis: Vector3::unit_x(), min: Degrees(20.0), max: Degrees(120.0) };
+        solve_two_bone(&mut positions, &mut rotations, &Vector3::new(0.0, 0.0, 0.0), &pole, &limit);
+        assert_relative_eq!(bend(&positions), 20.0, epsilon = 1e-3);
+
+        let (mut positions, mut rotations) = leg();
+        let limit = JointLimit::Cone { max_angle: Degrees(90.0) };
+        let distance = solve_two_bone(&mut positions, &mut rotations, &Vector3::new(0.0, 1.5, 0.0), &pole, &limit);
+        assert_relative_eq!(bend(&positions), 90.0, epsilon = 1e-3);
+        assert_relative_eq!(distance, 2f32.sqrt() - 0.5, epsilon = 1e-5);
+        assert_relative_eq!(direction(positions[0], positions[2]), -Vector3::unit_y(), epsilon = 1e-5);
+    }
+}
+
+mod iterative {
+    use super::*;
+
+    type Solver = fn(&mut [Vector3], &mut [Quaternion], &[JointLimit], &Vector3, &SolverSettings) -> f32;
+    const SOLVERS: [Solver; 2] = [solve_ccd, solve_fabrik];
+
+    #[test]
+    fn reach() {
+        let settings = SolverSettings { max_iterations: 100, tolerance: 1e-4 };
+        for solver in SOLVERS.iter() {
+            let (mut positions, mut rotations) = chain(5);
+            let target = Vector3::new(1.5, 2.0, -1.0);
+            let distance = solver(&mut positions, &mut rotations, &[], &target, &settings);
+            assert!(distance <= 1e-4);
+            assert_relative_eq!((positions[4] - target).length(), distance);
+            assert_eq!(positions[0], Vector3::default());
+            assert_rigid(&positions, &rotations);
+        }
+    }
+
+    #[test]
+    fn out_of_reach() {
+        for solver in SOLVERS.iter() {
+            let (mut positions, mut rotations) = chain(4);
+            let target = Vector3::new(10.0, 0.0, 0.0);
+            let distance = solver(&mut positions, &mut rotations, &[], &target, &SolverSettings::default());
+            assert_relative_eq!(distance, 7.0, epsilon = 1e-2);
+            assert_relative_eq!(positions[3], Vector3::new(3.0, 0.0, 0.0), epsilon = 1e-2);
+            assert_rigid(&positions, &rotations);
+        }
+    }
+
+    #[test]
+    fn settings() {
+        let target = Vector3::new(1.0, 1.0, 0.0);
+        for solver in SOLVERS.iter() {
+            let (mut positions, mut rotations) = chain(3);
+            let settings = SolverSettings { max_iterations: 0, ..SolverSettings::default() };
+            let distance = solver(&mut positions, &mut rotations, &[], &target, &settings);
+            assert_eq!((positions, distance), (chain(3).0, 2f32.sqrt()));
+
+            // A loose tolerance stops early, before the chain has reached the target exactly.
+            let (mut positions, mut rotations) = chain(3);
+            let settings = SolverSettings { max_iterations: 100, tolerance: 0.2 };
+            let loose = solver(&mut positions, &mut rotations, &[], &target, &settings);
+            assert!(loose <= 0.2);
+        }
+    }
+
+    #[test]
+    fn cone_limits() {
+        let limits = [JointLimit::Cone { max_angle: Degrees(30.0) }; 4];
+        let settings = SolverSettings { max_iterations: 200, tolerance: 1e-4 };
+        for solver in SOLVERS.iter() {
+            let (mut positions, mut rotations) = chain(5);
+            // Curling back to the root is impossible with these limits.
+            solver(&mut positions, &mut rotations, &limits, &Vector3::new(0.5, 0.0, 0.0), &settings);
+            assert!(angle_between(Vector3::unit_y(), positions[1] - positions[0]) <= 30.01);
+            for i in 1..4 {
+                assert!(angle_between(positions[i] - positions[i - 1], positions[i + 1] - positions[i]) <= 30.01);
+            }
+            assert_rigid(&positions, &rotations);
+
+            // A target within the limits is still reached.
+            let (mut positions, mut rotations) = chain(5);
+            let distance = solver(&mut positions, &mut rotations, &limits, &Vector3::new(1.0, 3.5, 0.0), &settings);
+            // Limited CCD converges slowly, so this is close rather than exact.
+            assert!(distance <= 1e-2);
+        }
+    }
+
+    #[test]
+    fn hinge_limits() {
+        // Every joint is a hinge around its local X axis, which only bends towards positive angles.
+        let limits = [JointLimit::Hinge { axis: Vector3::unit_x(), min: Degrees(0.0), max: Degrees(90.0) }; 3];
+        let hinge = Vector3::unit_x() * chain(1).1[0];
+        let bent = Vector3::unit_y() * Quaternion::from_axis_angle(&hinge, Degrees(45.0));
+        let settings = SolverSettings { max_iterations: 200, tolerance: 1e-4 };
+        for solver in SOLVERS.iter() {
+            let (mut positions, mut rotations) = chain(4);
+            let target = Vector3::new(0.0, 1.0, 0.0) + bent * 1.5;
+            let distance = solver(&mut positions, &mut rotations, &limits, &target, &settings);
+            assert!(distance <= 1e-3);
+            for i in 0..3 {
+                // Every bone stays in the plane the hinges bend in, on the allowed side.
+                let bone = positions[i + 1] - positions[i];
+                assert_relative_eq!(bone.dot(&hinge), 0.0, epsilon = 1e-4);
+                assert!(bone.dot(&bent) >= -1e-4 || bone.dot(&Vector3::unit_y()) >= 0.0);
+            }
+            assert_rigid(&positions, &rotations);
+
+            // Bending the other way is not allowed, so the chain stays straight at best.
+            let (mut positions, mut rotations) = chain(4);
+            let mirrored = Vector3::new(bent.x, bent.y, -bent.z);
+            solver(&mut positions, &mut rotations, &limits, &(Vector3::new(0.0, 1.0, 0.0) + mirrored * 1.5), &settings);
+            for i in 0..3 {
+                assert!((positions[i + 1] - positions[i]).dot(&mirrored) <= (positions[i + 1] - positions[i]).dot(&bent) + 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_rotations() {
+        let (mut positions, _) = chain(3);
+        solve_ccd(&mut positions, &mut [Quaternion::identity()], &[], &Vector3::default(), &SolverSettings::default());
+    }
+}
@@ -24,7 +24,7 @@ mod matrix1x3 {
             c02: 9.0,
         };
         assert_ulps_eq!(A + B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat += B;
         assert_ulps_eq!(mat, result);
     }
@@ -37,7 +37,7 @@ mod matrix1x3 {
             c02: -3.0,
         };
         assert_ulps_eq!(A - B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat -= B;
         assert_ulps_eq!(mat, result);
     }
@@ -51,14 +51,34 @@ mod matrix1x3 {
             c02: 6.0,
         };
         assert_ulps_eq!(A * scalar, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat *= scalar;
         assert_ulps_eq!(mat, result);
     }
+
+    #[test]
+    fn divide_scalar() {
+        let result = Matrix1x3 {
+            c00: 0.5,
+            c01: 1.0,
+            c02: 1.5,
+        };
+        assert_ulps_eq!(A / 2.0, result);
+        let mut mat = A;
+        mat /= 2.0;
+        assert_ulps_eq!(mat, result);
+    }
+
+    #[test]
+    fn negate() {
+        assert_ulps_eq!(-A, A * -1.0);
+    }
 }
 
 mod matrix2x2 {
-    use ezcgmath::matrix::Matrix2x2;
+    use ezcgmath::{Degrees, Error};
+    use ezcgmath::matrix::{Affine2D, Matrix2x2};
+    use ezcgmath::vector::Vector2;
 
     const A: Matrix2x2 = Matrix2x2 {
         c00: 1.0, c10: 2.0,
@@ -77,7 +97,7 @@ mod matrix2x2 {
             c01: 10.0, c11: 12.0,    
         };
         assert_ulps_eq!(A + B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat += B;
         assert_ulps_eq!(mat, result);
     }
@@ -89,7 +109,7 @@ mod matrix2x2 {
             c01: -4.0, c11: -4.0,    
         };
         assert_ulps_eq!(A - B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat -= B;
         assert_ulps_eq!(mat, result);
     }
@@ -102,7 +122,7 @@ mod matrix2x2 {
             c01: 6.0, c11: 8.0,    
         };
         assert_ulps_eq!(A * scalar, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat *= scalar;
         assert_ulps_eq!(mat, result);
     }
@@ -121,10 +141,131 @@ mod matrix2x2 {
         assert_ulps_eq!(A.determinant(), -2.0);
         assert_ulps_eq!(B.determinant(), -2.0);
     }
+    #[test]
+    fn divide_scalar() {
+        let result = Matrix2x2 {
+            c00: 0.5, c10: 1.0,
+            c01: 1.5, c11: 2.0,
+        };
+        assert_ulps_eq!(A / 2.0, result);
+        let mut mat = A;
+        mat /= 2.0;
+        assert_ulps_eq!(mat, result);
+    }
+
+    #[test]
+    fn negate() {
+        let result = Matrix2x2 {
+            c00: -1.0, c10: -2.0,
+            c01: -3.0, c11: -4.0,
+        };
+        assert_ulps_eq!(-A, result);
+    }
+
+    #[test]
+    fn multiply_vector() {
+        let v = Vector2::new(1.0, 1.0);
+        assert_ulps_eq!(v * A, Vector2::new(4.0, 6.0));
+        let mut vec = v;
+        vec *= A;
+        assert_ulps_eq!(vec, Vector2::new(4.0, 6.0));
+        assert_ulps_eq!(v * A * B, v * (A * B));
+    }
+
+    #[test]
+    fn transpose() {
+        let result = Matrix2x2 {
+            c00: 1.0, c10: 3.0,
+            c01: 2.0, c11: 4.0,
+        };
+        assert_ulps_eq!(A.transpose(), result);
+        assert_ulps_eq!((A * B).transpose(), B.transpose() * A.transpose());
+    }
+
+    #[test]
+    fn trace() {
+        assert_ulps_eq!(A.trace(), 5.0);
+        assert_ulps_eq!(B.trace(), 13.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let result = Matrix2x2 {
+            c00: -2.0, c10: 1.0,
+            c01: 1.5, c11: -0.5,
+        };
+        assert_ulps_eq!(A.inverse(), result);
+        assert_ulps_eq!(A * A.inverse(), Matrix2x2::identity());
+    }
+
+    #[test]
+    fn try_inverse() {
+        let singular = Matrix2x2 {
+            c00: 1.0, c10: 2.0,
+            c01: 2.0, c11: 4.0,
+        };
+        assert_eq!(singular.try_inverse(1e-6), Err(Error::SingularMatrix));
+        assert_eq!(Matrix2x2::default().checked_inverse(0.0), None);
+        assert_eq!(A.checked_inverse(1e-6), Some(A.inverse()));
+    }
+
+    #[test]
+    fn from_angle() {
+        let rotation = Matrix2x2::from_angle(Degrees(90.0));
+        assert_relative_eq!(Vector2::new(1.0, 0.0) * rotation, Vector2::new(0.0, -1.0), epsilon = 1e-6);
+        assert_relative_eq!(rotation.determinant(), 1.0);
+        assert_relative_eq!(rotation.inverse(), rotation.transpose(), epsilon = 1e-6);
+        assert_eq!(Affine2D::from_rotation(Degrees(33.0)).linear(), Matrix2x2::from_angle(Degrees(33.0)));
+    }
+
+    #[test]
+    fn from_scale() {
+        assert_eq!(Vector2::new(1.0, 2.0) * Matrix2x2::from_scale(3.0), Vector2::new(3.0, 6.0));
+        assert_eq!(Vector2::new(1.0, 2.0) * Matrix2x2::from_nonuniform_scale(&Vector2::new(3.0, -1.0)), Vector2::new(3.0, -2.0));
+    }
+
+    #[test]
+    fn eigenvalues() {
+        // An inertia tensor, with principal moments of 5 and 1.
+        let inertia = Matrix2x2 {
+            c00: 3.0, c10: 2.0,
+            c01: 2.0, c11: 3.0,
+        };
+        assert_eq!(inertia.eigenvalues(), Some((5.0, 1.0)));
+        assert_eq!(Matrix2x2::from_nonuniform_scale(&Vector2::new(2.0, 7.0)).eigenvalues(), Some((7.0, 2.0)));
+        let (larger, smaller) = B.eigenvalues().unwrap();
+        assert_relative_eq!(larger + smaller, B.trace(), epsilon = 1e-5);
+        assert_relative_eq!(larger * smaller, B.determinant(), epsilon = 1e-4);
+        assert_eq!(Matrix2x2::from_angle(Degrees(45.0)).eigenvalues(), None);
+    }
+
+    #[test]
+    fn eigenvalues_nearly_equal() {
+        // The eigenvalues of a symmetric matrix stay real, even when they are almost the same.
+        let (larger, smaller) = Matrix2x2::from_nonuniform_scale(&Vector2::new(0.1003, 0.100_300_04)).eigenvalues().unwrap();
+        assert_ulps_eq!(larger, 0.100_300_04);
+        assert_ulps_eq!(smaller, 0.1003);
+        for i in 0..100 {
+            let diagonal = 0.1 + i as f32 * 1e-3;
+            let matrix = Matrix2x2::from_nonuniform_scale(&Vector2::new(diagonal, diagonal + 1e-7));
+            assert!(matrix.eigenvalues().is_some());
+        }
+
+        let small_coupling = Matrix2x2 {
+            c00: 1.0, c10: 1e-4,
+            c01: 1e-4, c11: 1.0,
+        };
+        let (larger, smaller) = small_coupling.eigenvalues().unwrap();
+        assert_relative_eq!(larger, 1.0 + 1e-4, epsilon = 1e-7);
+        assert_relative_eq!(smaller, 1.0 - 1e-4, epsilon = 1e-7);
+    }
 }
 
 mod matrix3x3 {
-    use ezcgmath::matrix::Matrix3x3;
+    use ezcgmath::{Degrees, Error};
+    use ezcgmath::matrix::{Matrix3x3, Matrix4x4};
+    use ezcgmath::quaternion::Quaternion;
+    use ezcgmath::vector::Vector3;
 
     const A: Matrix3x3 = Matrix3x3 {
         c00: 1.0, c10: 2.0, c20: 3.0,
@@ -146,7 +287,7 @@ mod matrix3x3 {
             c02: 23.0, c12: 25.0, c22: 27.0,
         };
         assert_ulps_eq!(A + B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat += B;
         assert_ulps_eq!(mat, result);
     }
@@ -159,7 +300,7 @@ mod matrix3x3 {
             c02: -9.0, c12: -9.0, c22: -9.0,
         };
         assert_ulps_eq!(A - B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat -= B;
         assert_ulps_eq!(mat, result);
     }
@@ -173,7 +314,7 @@ mod matrix3x3 {
             c02: 14.0, c12: 16.0, c22: 18.0,
         };
         assert_ulps_eq!(A * scalar, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat *= scalar;
         assert_ulps_eq!(mat, result);
     }
@@ -247,18 +388,152 @@ mod matrix3x3 {
                 c02: 7.0, c12: 59.0, c22: 86.0,    
             }.inverse(),
             Matrix3x3 {
-                c00: -0.02975909654, c10:  0.01981482574,   c20:  0.001385858348,
-                c01:  0.02139971693, c11: -0.003597334434,  c21: -0.001739694521,
-                c02: -0.01225894911, c12:  0.0008551040868, c22:  0.01270861591,
+                c00: -0.029_759_096, c10:  0.019_814_827,   c20:  0.001_385_858_4,
+                c01:  0.021_399_718, c11: -0.003_597_334_5,  c21: -0.001_739_694_5,
+                c02: -0.012_258_949, c12:  0.000_855_104_07, c22:  0.012_708_615_5,
             }
         );
     }
+
+    #[test]
+    fn try_inverse() {
+        assert_eq!(A.try_inverse(1e-6), Err(Error::SingularMatrix));
+        assert_eq!(Matrix3x3::default().checked_inverse(0.0), None);
+        let nearly_singular = Matrix3x3 { c22: 1e-7, ..Matrix3x3::identity() };
+        assert_eq!(nearly_singular.try_inverse(1e-6), Err(Error::SingularMatrix));
+        assert_ulps_eq!(nearly_singular.try_inverse(0.0).unwrap(), Matrix3x3 { c22: 1e7, ..Matrix3x3::identity() });
+        let invertible = Matrix3x3 { c00: 2.0, c11: 4.0, c22: 8.0, ..Matrix3x3::default() };
+        assert_eq!(invertible.checked_inverse(1e-6), Some(invertible.inverse()));
+    }
+
+    #[test]
+    fn from_axis_angle() {
+        let mut axis = Vector3::new(1.0, -2.0, 0.5);
+        axis.normalize();
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(70.0));
+        assert_relative_eq!(Matrix3x3::from_axis_angle(&axis, Degrees(70.0)), Matrix3x3::from(Matrix4x4::from(rotation)), epsilon = 1e-6);
+        assert_relative_eq!(Matrix3x3::from_rotation_x(Degrees(30.0)), Matrix3x3::from_axis_angle(&Vector3::unit_x(), Degrees(30.0)));
+        assert_relative_eq!(Matrix3x3::from_rotation_y(Degrees(30.0)), Matrix3x3::from_axis_angle(&Vector3::unit_y(), Degrees(30.0)));
+        assert_relative_eq!(Matrix3x3::from_rotation_z(Degrees(30.0)), Matrix3x3::from_axis_angle(&Vector3::unit_z(), Degrees(30.0)));
+
+        let v = Vector3::new(3.0, 1.0, -2.0);
+        assert_relative_eq!(v * Matrix3x3::from_rotation_y(Degrees(-45.0)), v * Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(-45.0)), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn from_basis() {
+        let right = Vector3::new(0.0, 0.0, -1.0);
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let forward = Vector3::new(1.0, 0.0, 0.0);
+        let basis = Matrix3x3::from_basis(&right, &up, &forward);
+        assert_eq!(Vector3::unit_x() * basis, right);
+        assert_eq!(Vector3::unit_y() * basis, up);
+        assert_eq!(Vector3::unit_z() * basis, forward);
+    }
+
+    #[test]
+    fn from_normal() {
+        let mut normal = Vector3::new(0.3, -0.4, 0.5);
+        normal.normalize();
+        let frame = Matrix3x3::from_normal(&normal);
+        assert!(frame.is_rotation(1e-5));
+        assert_relative_eq!(Vector3::unit_z() * frame, normal, epsilon = 1e-6);
+        // Looking straight down is where a look-at with a Y up vector breaks.
+        let down = Matrix3x3::from_normal(&-Vector3::unit_y());
+        assert!(down.is_rotation(1e-6));
+        assert_relative_eq!(Vector3::unit_z() * down, -Vector3::unit_y());
+        assert!(Matrix3x3::from_normal(&-Vector3::unit_z()).is_rotation(1e-6));
+    }
+
+    #[test]
+    fn from_look_at() {
+        let tilted_up = Matrix3x3::from_look_at(Vector3::new(0.0, 0.0, 2.0), Vector3::new(0.0, 1.0, 1.0));
+        assert_ulps_eq!(tilted_up, Matrix3x3::identity());
+
+        let skewed = Matrix3x3::from_look_at(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.2, 1.0, 0.3));
+        assert!(skewed.is_rotation(1e-5));
+        let mut forward = Vector3::new(1.0, -2.0, 0.5);
+        forward.normalize();
+        assert_relative_eq!(Vector3::unit_z() * skewed, forward, epsilon = 1e-6);
+
+        // Cameras looking straight down or up fall back to Z as up, continuing the rotation just before it.
+        let down = Matrix3x3::from_look_at(-Vector3::unit_y(), Vector3::unit_y());
+        assert!(down.is_rotation(1e-6));
+        assert_relative_eq!(Vector3::unit_y() * down, Vector3::unit_z());
+        assert_relative_eq!(Vector3::unit_z() * down, -Vector3::unit_y());
+        let nearly_down = Matrix3x3::from_look_at(Vector3::new(0.0, -1.0, 0.001), Vector3::unit_y());
+        assert_relative_eq!(nearly_down, down, epsilon = 1e-3);
+        let up = Matrix3x3::from_look_at(Vector3::unit_y(), Vector3::new(0.0, 2.0, 0.0));
+        assert!(up.is_rotation(1e-6));
+
+        let along_z = Matrix3x3::from_look_at(Vector3::unit_z(), -Vector3::unit_z());
+        assert_ulps_eq!(along_z, Matrix3x3::identity());
+    }
+
+    #[test]
+    fn from_reflection() {
+        let normal = Vector3::new(1.0, 1.0, 0.0);
+        let reflection = Matrix3x3::from_reflection(&normal);
+        assert_relative_eq!(normal * reflection, -normal, epsilon = 1e-6);
+        assert_relative_eq!(Vector3::new(1.0, -1.0, 5.0) * reflection, Vector3::new(1.0, -1.0, 5.0), epsilon = 1e-6);
+        assert_relative_eq!(reflection * reflection, Matrix3x3::identity(), epsilon = 1e-6);
+        assert_relative_eq!(reflection.determinant(), -1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn from_shear() {
+        let shear = Matrix3x3::from_shear(2.0, 0.0, 0.0, 0.0, 0.0, -1.0);
+        assert_eq!(Vector3::new(0.0, 1.0, 0.0) * shear, Vector3::new(2.0, 1.0, -1.0));
+        assert_eq!(Vector3::new(1.0, 0.0, 1.0) * shear, Vector3::new(1.0, 0.0, 1.0));
+        assert_eq!(Matrix3x3::from_shear(1.0, 2.0, 3.0, 4.0, 5.0, 6.0).transpose(), Matrix3x3::from_shear(3.0, 5.0, 1.0, 6.0, 2.0, 4.0));
+    }
+
+    #[test]
+    fn orthonormalize() {
+        // Accumulating rotations in single precision slowly adds scale and skew.
+        let step = Matrix3x3::from_axis_angle(&Vector3::new(0.0, 0.6, 0.8), Degrees(0.7)) * Matrix3x3::from_rotation_x(Degrees(1.3));
+        let mut drifting = Matrix3x3::identity();
+        for _ in 0..100_000 {
+            drifting *= step;
+        }
+        assert!(!drifting.is_orthonormal(1e-4));
+        let repaired = drifting.orthonormalize();
+        assert!(repaired.is_rotation(1e-6));
+        assert_relative_eq!(repaired, drifting, epsilon = 1e-2);
+
+        let skewed = Matrix3x3::from_shear(0.0, 0.0, 0.5, 0.0, 0.2, 0.1) * 3.0;
+        let repaired = skewed.orthonormalize();
+        assert!(repaired.is_rotation(1e-6));
+        let mut first_row = Vector3::new(skewed.c00, skewed.c10, skewed.c20);
+        first_row.normalize();
+        assert_relative_eq!(Vector3::new(repaired.c00, repaired.c10, repaired.c20), first_row, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn is_orthonormal() {
+        assert!(Matrix3x3::identity().is_orthonormal(0.0));
+        assert!(Matrix3x3::from_rotation_z(Degrees(30.0)).is_orthonormal(1e-6));
+        assert!(!(Matrix3x3::identity() * 1.01).is_orthonormal(1e-3));
+        assert!(!Matrix3x3::from_shear(0.01, 0.0, 0.0, 0.0, 0.0, 0.0).is_orthonormal(1e-3));
+    }
+
+    #[test]
+    fn is_rotation() {
+        assert!(Matrix3x3::from_rotation_y(Degrees(-80.0)).is_rotation(1e-6));
+        let reflection = Matrix3x3::from_reflection(&Vector3::unit_z());
+        assert!(reflection.is_orthonormal(1e-6));
+        assert!(!reflection.is_rotation(1e-6));
+        assert!(reflection.orthonormalize().is_orthonormal(1e-6));
+        assert!(!reflection.orthonormalize().is_rotation(1e-6));
+        assert!(reflection.nearest_rotation().is_rotation(1e-5));
+    }
 }
 
 mod matrix4x4 {
-    use ezcgmath::{Degrees, Radians};
-    use ezcgmath::matrix::Matrix4x4;
+    use ezcgmath::{Degrees, Error, Radians};
+    use ezcgmath::matrix::{Matrix3x3, Matrix4x4};
     use ezcgmath::quaternion::Quaternion;
+    use ezcgmath::vector::Vector3;
 
     const A: Matrix4x4 = Matrix4x4 {
         c00: 1.0, c10: 2.0, c20: 3.0, c30: 4.0,
@@ -283,7 +558,7 @@ mod matrix4x4 {
             c03: 42.0, c13: 44.0, c23: 46.0, c33: 48.0,    
         };
         assert_ulps_eq!(A + B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat += B;
         assert_ulps_eq!(mat, result);
     }
@@ -297,7 +572,7 @@ mod matrix4x4 {
             c03: -16.0, c13: -16.0, c23: -16.0, c33: -16.0,    
         };
         assert_ulps_eq!(A - B, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat -= B;
         assert_ulps_eq!(mat, result);
     }
@@ -312,7 +587,7 @@ mod matrix4x4 {
             c03: 26.0, c13: 28.0, c23: 30.0, c33: 32.0,
         };
         assert_ulps_eq!(A * scalar, result);
-        let mut mat = A.clone();
+        let mut mat = A;
         mat *= scalar;
         assert_ulps_eq!(mat, result);
     }
@@ -439,11 +714,11 @@ mod matrix4x4 {
         // Running this test ensures nothing is "wrong" when you do quaternion * Matrix4x4.
         // That's because quaternion * Matrix4x4 is actually Matrix4x4::from(quaternion) * Matrix4x4.
         // So it's really just Matrix4x4 * Matrix4x4. Any issues with that will get caught by a different test.
-        let matrix_from_quaternion = Matrix4x4::from(Quaternion { x: 0.18257418583505536, y: 0.3651483716701107, z: 0.5477225575051661, w: 0.7302967433402214 });
+        let matrix_from_quaternion = Matrix4x4::from(Quaternion { x: 0.182_574_18, y: 0.365_148_37, z: 0.547_722_6, w: 0.730_296_73 });
         let result = Matrix4x4 {
-            c00: 0.13333333333333353, c10: -0.6666666666666666, c20: 0.7333333333333332, c30: 0.0,
-            c01: 0.9333333333333332, c11: 0.3333333333333335, c21: 0.13333333333333336, c31: 0.0,
-            c02: -0.33333333333333326, c12: 0.6666666666666665, c22: 0.6666666666666667, c32: 0.0,
+            c00: 0.133_333_34, c10: -0.666_666_7, c20: 0.733_333_35, c30: 0.0,
+            c01: 0.933_333_34, c11: 0.333_333_34, c21: 0.133_333_34, c31: 0.0,
+            c02: -0.333_333_34, c12: 0.666_666_7, c22: 0.666_666_7, c32: 0.0,
             c03: 0.0, c13: 0.0, c23: 0.0, c33: 1.0,
         };
         assert_ulps_eq!(matrix_from_quaternion, result);
@@ -515,11 +790,689 @@ mod matrix4x4 {
                 c03: 63.0, c13: -3.0, c23: -23.0, c33: 51.0,
             }.inverse(),
             Matrix4x4 {
-                c00: -0.02144504449, c10: -0.01894554558, c20: 0.04831673192, c30: 0.02845612386,
-                c01: 0.01240283943, c11: 0.0147995753, c21: -0.01748989292, c31: -0.005001071941,
-                c02: 0.00992408168, c12: -0.01415471363, c22: 0.03197835075, c32: -0.008288735115,
-                c03: 0.03169608235, c13: 0.01789038596, c23: -0.04629258279, c33: -0.01957607699,
+                c00: -0.021_445_045, c10: -0.018_945_545, c20: 0.048_316_732, c30: 0.028_456_124,
+                c01: 0.012_402_839, c11: 0.014_799_575, c21: -0.017_489_893, c31: -0.005_001_072,
+                c02: 0.009_924_082, c12: -0.014_154_714, c22: 0.031_978_35, c32: -0.008_288_735_5,
+                c03: 0.031_696_08, c13: 0.017_890_386, c23: -0.046_292_584, c33: -0.019_576_076,
             }
         );
     }
-}
\ No newline at end of file
+
+    fn general_inverse(matrix: &Matrix4x4) -> Matrix4x4 {
+        matrix.matrix_of_minors().matrix_of_cofactors().transpose() * (1.0 / matrix.determinant())
+    }
+
+    fn rigid_transform() -> Matrix4x4 {
+        Matrix4x4::from(Quaternion::from_euler(Degrees(30.0), Degrees(-50.0), Degrees(110.0)))
+            * Matrix4x4::from_translation(&Vector3::new(120.0, -35.5, 8.25))
+    }
+
+    #[test]
+    fn is_affine() {
+        assert!(Matrix4x4::identity().is_affine());
+        assert!(rigid_transform().is_affine());
+        assert!(!Matrix4x4::new_perspective_projection(Degrees(60.0), 1.5, 0.1, 100.0).is_affine());
+        assert!(!A.is_affine());
+    }
+
+    #[test]
+    fn inverse_affine() {
+        let matrix = Matrix4x4::from_nonuniform_scale(&Vector3::new(2.0, 0.5, 3.0)) * rigid_transform();
+        let inverse = matrix.inverse_affine();
+        assert_relative_eq!(inverse, general_inverse(&matrix), epsilon = 1e-5, max_relative = 1e-5);
+        assert_relative_eq!(matrix * inverse, Matrix4x4::identity(), epsilon = 1e-5);
+        assert_eq!(matrix.inverse(), inverse);
+        assert_eq!(matrix.try_inverse(1e-6), Ok(inverse));
+        assert_eq!(Matrix4x4::from_scale(0.0).try_inverse(1e-6), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn inverse_rigid() {
+        let matrix = rigid_transform();
+        let inverse = matrix.inverse_rigid();
+        assert_relative_eq!(inverse, general_inverse(&matrix), epsilon = 1e-5, max_relative = 1e-5);
+        assert_relative_eq!(inverse, matrix.inverse_affine(), epsilon = 1e-5, max_relative = 1e-5);
+        assert_relative_eq!(matrix * inverse, Matrix4x4::identity(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn inverse_affine_accuracy() {
+        // Translations far from the origin lose precision through the cofactors of the general path.
+        let matrix = Matrix4x4::from_translation(&Vector3::new(12_345.0, -6_789.0, 2_468.0)) * rigid_transform();
+        let affine_error = matrix * matrix.inverse_affine() - Matrix4x4::identity();
+        let general_error = matrix * general_inverse(&matrix) - Matrix4x4::identity();
+        let max_error = |m: Matrix4x4| {
+            [m.c00, m.c10, m.c20, m.c01, m.c11, m.c21, m.c02, m.c12, m.c22, m.c03, m.c13, m.c23]
+                .iter()
+                .fold(0.0_f32, |max, value| max.max(value.abs()))
+        };
+        assert!(max_error(affine_error) <= max_error(general_error));
+        assert!(max_error(affine_error) < 1e-2);
+    }
+
+    #[test]
+    fn transform_point_and_vector() {
+        let matrix = Matrix4x4::from_nonuniform_scale(&Vector3::new(2.0, 3.0, 4.0))
+            * Matrix4x4::from_translation(&Vector3::new(10.0, 20.0, 30.0));
+        let v = Vector3::new(1.0, 1.0, 1.0);
+        assert_ulps_eq!(matrix.transform_point3(&v), Vector3::new(12.0, 23.0, 34.0));
+        assert_ulps_eq!(matrix.transform_vector3(&v), Vector3::new(2.0, 3.0, 4.0));
+        assert_ulps_eq!(matrix.transform_vector3(&v), v * matrix);
+    }
+
+    #[test]
+    fn transform_normal() {
+        let matrix = Matrix4x4::from_nonuniform_scale(&Vector3::new(4.0, 1.0, 1.0))
+            * Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(30.0))
+            * Matrix4x4::from_translation(&Vector3::new(10.0, 20.0, 30.0));
+        // The plane x + y = 0 has the normal (1, 1, 0) and contains the tangent (1, -1, 0).
+        let normal = Vector3::new(1.0, 1.0, 0.0);
+        let tangent = Vector3::new(1.0, -1.0, 0.0);
+        let transformed_tangent = matrix.transform_vector3(&tangent);
+        assert_ulps_eq!(matrix.transform_normal(&normal).dot(&transformed_tangent), 0.0, epsilon = 1e-5);
+        assert!(matrix.transform_vector3(&normal).dot(&transformed_tangent).abs() > 1.0);
+
+        let rotation = Matrix4x4::from(Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(45.0)));
+        assert_relative_eq!(rotation.normal_matrix(), Matrix3x3::from(rotation), epsilon = 1e-6);
+        assert_eq!(matrix.try_normal_matrix(1e-6), Ok(matrix.normal_matrix()));
+        assert_eq!(matrix.try_transform_normal(&normal, 1e-6), Ok(matrix.transform_normal(&normal)));
+
+        // Flattening onto a plane has no normal matrix.
+        let flatten = Matrix4x4::from_nonuniform_scale(&Vector3::new(1.0, 0.0, 1.0));
+        assert!(!flatten.transform_normal(&normal).x.is_finite());
+        assert_eq!(flatten.try_normal_matrix(1e-6), Err(Error::SingularMatrix));
+        assert_eq!(flatten.try_transform_normal(&normal, 1e-6), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn orthonormalize() {
+        let drifted = Matrix3x3::from_rotation_x(Degrees(20.0)) * 1.001 + Matrix3x3 { c21: 0.002, ..Matrix3x3::default() };
+        let mut matrix = Matrix4x4::from(Quaternion::identity()) * Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+        matrix.c00 = drifted.c00; matrix.c10 = drifted.c10; matrix.c20 = drifted.c20;
+        matrix.c01 = drifted.c01; matrix.c11 = drifted.c11; matrix.c21 = drifted.c21;
+        matrix.c02 = drifted.c02; matrix.c12 = drifted.c12; matrix.c22 = drifted.c22;
+        assert!(!matrix.is_orthonormal(1e-4));
+
+        for repaired in [matrix.orthonormalize(), matrix.nearest_rotation()].iter() {
+            assert!(repaired.is_rotation(1e-5));
+            assert_eq!(Vector3::new(repaired.c03, repaired.c13, repaired.c23), Vector3::new(1.0, 2.0, 3.0));
+            assert!(repaired.is_affine());
+            assert_relative_eq!(Matrix3x3::from(*repaired), Matrix3x3::from_rotation_x(Degrees(20.0)), epsilon = 1e-2);
+        }
+        assert!(!Matrix4x4::from_scale(2.0).is_rotation(1e-3));
+        assert!(Matrix4x4::from_translation(&Vector3::new(5.0, 0.0, 0.0)).is_rotation(0.0));
+    }
+
+    #[test]
+    fn rotation_constructors() {
+        let mut axis = Vector3::new(-1.0, 2.0, 2.0);
+        axis.normalize();
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(-120.0));
+        assert_relative_eq!(Matrix4x4::from_axis_angle(&axis, Degrees(-120.0)), Matrix4x4::from(rotation), epsilon = 1e-6);
+        assert_relative_eq!(Matrix4x4::from_rotation_x(Degrees(10.0)), Matrix4x4::from(Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(10.0))), epsilon = 1e-6);
+        assert_relative_eq!(Matrix4x4::from_rotation_y(Degrees(20.0)), Matrix4x4::from(Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(20.0))), epsilon = 1e-6);
+        assert_relative_eq!(Matrix4x4::from_rotation_z(Degrees(30.0)), Matrix4x4::from(Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(30.0))), epsilon = 1e-6);
+        let basis = Matrix4x4::from_basis(&Vector3::unit_y(), &Vector3::unit_z(), &Vector3::unit_x());
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0) * basis, Vector3::new(3.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn from_reflection() {
+        // The plane y = 2, as used to render a water surface.
+        let reflection = Matrix4x4::from_reflection(&Vector3::new(0.0, 2.0, 0.0), 2.0);
+        assert_relative_eq!(reflection.transform_point3(&Vector3::new(1.0, 5.0, 3.0)), Vector3::new(1.0, -1.0, 3.0));
+        assert_relative_eq!(reflection.transform_point3(&Vector3::new(-4.0, 2.0, 7.0)), Vector3::new(-4.0, 2.0, 7.0));
+        assert_relative_eq!(reflection.transform_vector3(&Vector3::new(0.0, 1.0, 1.0)), Vector3::new(0.0, -1.0, 1.0));
+        assert_relative_eq!(reflection * reflection, Matrix4x4::identity());
+        assert!(reflection.is_affine());
+    }
+
+    #[test]
+    fn from_shear() {
+        let shear = Matrix4x4::from_shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(shear.transform_point3(&Vector3::new(0.0, 4.0, 1.0)), Vector3::new(2.0, 4.0, 1.0));
+        assert_eq!(Matrix3x3::from(shear), Matrix3x3::from_shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn try_inverse() {
+        assert_eq!(A.try_inverse(1e-6), Err(Error::SingularMatrix));
+        assert_eq!(Matrix4x4::from_scale(0.0).checked_inverse(0.0), None);
+        let translation = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+        assert_ulps_eq!(translation.try_inverse(1e-6).unwrap(), Matrix4x4::from_translation(&Vector3::new(-1.0, -2.0, -3.0)));
+    }
+}
+
+mod dmatrix4x4 {
+    use ezcgmath::matrix::{DMatrix4x4, Matrix4x4};
+    use ezcgmath::vector::DVector3;
+
+    #[test]
+    fn inverse_far_from_origin() {
+        let translation = DVector3::new(1.0e9, -2.0e9, 3.5e8);
+        let inverse = DMatrix4x4::from_translation(&translation).inverse();
+        assert_relative_eq!(inverse, DMatrix4x4::from_translation(&-translation), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn precision_conversion() {
+        let single = Matrix4x4::from_scale(0.1);
+        assert_eq!(single.to_f64().to_f32(), single);
+        assert_eq!(DMatrix4x4::from_scale(0.1).to_f32(), single);
+    }
+}
+
+mod affine2d {
+    use ezcgmath::{Degrees, Error};
+    use ezcgmath::matrix::{Affine2D, Matrix3x3};
+    use ezcgmath::point::Point2;
+    use ezcgmath::vector::{Vector2, Vector3};
+
+    fn transform() -> Affine2D {
+        Affine2D::from_nonuniform_scale(&Vector2::new(2.0, 3.0))
+            * Affine2D::from_skew(Degrees(20.0), Degrees(-10.0))
+            * Affine2D::from_rotation(Degrees(35.0))
+            * Affine2D::from_translation(&Vector2::new(-4.0, 7.0))
+    }
+
+    #[test]
+    fn constructors() {
+        let v = Vector2::new(1.0, 2.0);
+        assert_eq!(Affine2D::from_translation(&Vector2::new(3.0, -1.0)).transform_point2(&v), Vector2::new(4.0, 1.0));
+        assert_eq!(Affine2D::from_translation(&Vector2::new(3.0, -1.0)).transform_vector2(&v), v);
+        assert_eq!(Affine2D::from_nonuniform_scale(&Vector2::new(2.0, 3.0)).transform_point2(&v), Vector2::new(2.0, 6.0));
+        assert_eq!(Affine2D::from_scale(2.0).transform_point2(&v), Vector2::new(2.0, 4.0));
+        assert_relative_eq!(Affine2D::from_rotation(Degrees(90.0)).transform_point2(&v), Vector2::new(2.0, -1.0), epsilon = 1e-6);
+        assert_relative_eq!(Affine2D::from_skew(Degrees(45.0), Degrees(0.0)).transform_point2(&v), Vector2::new(3.0, 2.0), epsilon = 1e-6);
+        assert_relative_eq!(Affine2D::from_skew(Degrees(0.0), Degrees(45.0)).transform_point2(&v), Vector2::new(1.0, 3.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn rotation_matches_matrix3x3() {
+        let rotation = Matrix3x3::from(Affine2D::from_rotation(Degrees(25.0)));
+        assert_relative_eq!(rotation, Matrix3x3::from_rotation_z(Degrees(25.0)));
+    }
+
+    #[test]
+    fn composition() {
+        let first = Affine2D::from_rotation(Degrees(90.0));
+        let second = Affine2D::from_translation(&Vector2::new(10.0, 0.0));
+        let v = Vector2::new(1.0, 0.0);
+        assert_relative_eq!((first * second).transform_point2(&v), second.transform_point2(&first.transform_point2(&v)), epsilon = 1e-6);
+        assert_relative_eq!((first * second).transform_point2(&v), Vector2::new(10.0, -1.0), epsilon = 1e-6);
+
+        let mut combined = first;
+        combined *= second;
+        assert_eq!(combined, first * second);
+
+        let homogeneous = Vector3::new(v.x, v.y, 1.0) * Matrix3x3::from(first * second);
+        assert_relative_eq!(Vector2::new(homogeneous.x, homogeneous.y), (first * second).transform_point2(&v), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = transform();
+        assert_relative_eq!(matrix * matrix.inverse(), Affine2D::identity(), epsilon = 1e-5);
+        assert_relative_eq!(matrix.inverse() * matrix, Affine2D::identity(), epsilon = 1e-5);
+        assert_relative_eq!(Matrix3x3::from(matrix.inverse()), Matrix3x3::from(matrix).inverse(), epsilon = 1e-5);
+        assert_relative_eq!(matrix.linear().determinant(), matrix.determinant());
+        assert_eq!(matrix.translation(), Vector2::new(matrix.c02, matrix.c12));
+    }
+
+    #[test]
+    fn try_inverse() {
+        assert_eq!(Affine2D::from_scale(0.0).try_inverse(1e-6), Err(Error::SingularMatrix));
+        assert_eq!(Affine2D::from_nonuniform_scale(&Vector2::new(1.0, 1e-8)).checked_inverse(1e-6), None);
+        let translation = Affine2D::from_translation(&Vector2::new(1.0, 2.0));
+        assert_eq!(translation.try_inverse(1e-6), Ok(Affine2D::from_translation(&Vector2::new(-1.0, -2.0))));
+    }
+
+    #[test]
+    fn point() {
+        let matrix = transform();
+        let point = Point2::new(0.5, -1.5);
+        assert_eq!(Vector2::from(point * matrix), matrix.transform_point2(&Vector2::from(point)));
+        let mut moved = point;
+        moved *= matrix;
+        assert_eq!(moved, point * matrix);
+    }
+
+    #[test]
+    fn precision_conversion() {
+        let matrix = transform();
+        assert_eq!(matrix.to_f64().to_f32(), matrix);
+    }
+}
+
+mod generic {
+    use ezcgmath::matrix::*;
+    use ezcgmath::quaternion::Quaternion;
+    use ezcgmath::vector::Vector3;
+    use ezcgmath::Degrees;
+
+    const A: Matrix2x3 = Matrix::from_rows([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+    ]);
+
+    const B: Matrix3x2 = Matrix::from_rows([
+        [7.0, 8.0],
+        [9.0, 10.0],
+        [11.0, 12.0],
+    ]);
+
+    fn affine() -> Matrix4x4 {
+        Matrix4x4::from_nonuniform_scale(&Vector3::new(1.0, 2.0, 3.0))
+            * Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(40.0))
+            * Matrix4x4::from_translation(&Vector3::new(4.0, 5.0, 6.0))
+    }
+
+    #[test]
+    fn identity() {
+        assert_eq!(Matrix::<2, 2>::identity(), Matrix::from(Matrix2x2::identity()));
+        assert_eq!(Matrix::<4, 4>::identity(), Matrix::from(Matrix4x4::identity()));
+        assert_eq!(Matrix2x3::identity(), Matrix::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]));
+        assert_eq!(Matrix3x2::identity() * A, Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [0.0, 0.0, 0.0]]));
+    }
+
+    #[test]
+    fn elements() {
+        assert_eq!(A[(1, 2)], 6.0);
+        assert_eq!(A.row(0), [1.0, 2.0, 3.0]);
+        assert_eq!(A.column(1), [2.0, 5.0]);
+        let mut mat = A;
+        mat[(0, 1)] = -1.0;
+        assert_eq!(mat.row(0), [1.0, -1.0, 3.0]);
+    }
+
+    #[test]
+    fn transpose() {
+        let transposed: Matrix3x2 = A.transpose();
+        assert_eq!(transposed, Matrix::from_rows([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+        assert_eq!(transposed.transpose(), A);
+        assert_eq!(Matrix::from(Matrix3x3::from_rotation_x(Degrees(30.0)).transpose()), Matrix::from(Matrix3x3::from_rotation_x(Degrees(30.0))).transpose());
+    }
+
+    #[test]
+    fn multiply_matrix() {
+        let product: Matrix<2, 2> = A * B;
+        assert_eq!(product, Matrix::from_rows([[58.0, 64.0], [139.0, 154.0]]));
+        let product: Matrix<3, 3> = B * A;
+        assert_eq!(product.row(0), [39.0, 54.0, 69.0]);
+        assert_eq!((A * B).transpose(), B.transpose() * A.transpose());
+
+        let a = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+        let b = affine();
+        assert_ulps_eq!(Matrix4x4::from(Matrix::from(a) * Matrix::from(b)), a * b);
+        let mut c = Matrix::from(a);
+        c *= Matrix::from(b);
+        assert_ulps_eq!(Matrix4x4::from(c), a * b);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(A + A, A * 2.0);
+        assert_eq!(A - A, Matrix2x3::zero());
+        assert_eq!(A * 2.0 / 2.0, A);
+        assert_eq!(-A + A, Matrix2x3::default());
+        let mut mat = A;
+        mat += A;
+        mat -= A;
+        mat *= 3.0;
+        mat /= 3.0;
+        assert_eq!(mat, A);
+    }
+
+    #[test]
+    fn named_conversions() {
+        let column = Matrix1x3 { c00: 1.0, c01: 2.0, c02: 3.0 };
+        let generic = Matrix::<3, 1>::from(column);
+        assert_eq!(generic.column(0), [1.0, 2.0, 3.0]);
+        assert_eq!(Matrix1x3::from(generic), column);
+        let rotation = Matrix3x3::from_rotation_z(Degrees(60.0));
+        assert_eq!(Matrix3x3::from(Matrix::from(rotation)), rotation);
+        assert_ulps_eq!(Matrix1x3::from(Matrix::from(rotation) * generic), rotation * column);
+        let rotation = Matrix2x2::from_angle(Degrees(60.0));
+        assert_eq!(Matrix2x2::from(Matrix::from(rotation)), rotation);
+    }
+
+    #[test]
+    fn affine_layouts() {
+        let matrix = affine();
+        let rows: Matrix4x3 = matrix.to_affine_rows();
+        let columns: Matrix3x4 = matrix.to_affine_columns();
+        assert_eq!(Matrix4x4::from(rows), matrix);
+        assert_eq!(Matrix4x4::from(columns), matrix);
+        assert_eq!(rows.row(3), [matrix.c03, matrix.c13, matrix.c23]);
+
+        // A row vector times the rows, or the columns times a column vector, both transform a point.
+        let point = Vector3::new(1.0, -2.0, 0.5);
+        let transformed = Matrix::from_rows([[point.x, point.y, point.z, 1.0]]) * rows;
+        assert_relative_eq!(Vector3::new(transformed[(0, 0)], transformed[(0, 1)], transformed[(0, 2)]), matrix.transform_point3(&point), epsilon = 1e-5);
+        let transformed = columns * Matrix::from_rows([[point.x], [point.y], [point.z], [1.0]]);
+        assert_relative_eq!(Vector3::new(transformed[(0, 0)], transformed[(1, 0)], transformed[(2, 0)]), matrix.transform_point3(&point), epsilon = 1e-5);
+        assert_eq!(std::mem::size_of::<Matrix3x4>(), 12 * std::mem::size_of::<f32>());
+    }
+
+    #[test]
+    fn precision_conversion() {
+        let double: DMatrix2x3 = A.into();
+        assert_eq!(double.to_f32(), A);
+        assert_eq!(DMatrix4x4::from(DMatrix::from(affine().to_f64())), affine().to_f64());
+    }
+}
+
+mod decomposition {
+    use ezcgmath::Error;
+    use ezcgmath::matrix::*;
+    use ezcgmath::vector::{DVector4, Vector3, Vector4};
+
+    const A: Matrix<3, 3> = Matrix::from_rows([
+        [2.0, 1.0, 1.0],
+        [4.0, -6.0, 0.0],
+        [-2.0, 7.0, 2.0],
+    ]);
+
+    fn hilbert() -> DMatrix<4, 4> {
+        let mut result = DMatrix::zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                result[(i, j)] = 1.0 / (i + j + 1) as f64;
+            }
+        }
+        result
+    }
+
+    fn permuted<const N: usize>(m: &Matrix<N, N>, permutation: [usize; N]) -> Matrix<N, N> {
+        let mut result = Matrix::zero();
+        for (i, p) in permutation.iter().enumerate() {
+            result.rows[i] = m.rows[*p];
+        }
+        result
+    }
+
+    #[test]
+    fn lu() {
+        let lu = A.lu();
+        assert_eq!(lu.permutation(), [1, 0, 2]);
+        assert_relative_eq!(lu.l() * lu.u(), permuted(&A, lu.permutation()), epsilon = 1e-6);
+        assert_eq!(lu.l()[(0, 1)], 0.0);
+        assert_eq!(lu.u()[(1, 0)], 0.0);
+        assert_relative_eq!(lu.determinant(), Matrix3x3::from(A).determinant(), epsilon = 1e-5);
+        assert!(!lu.is_singular());
+    }
+
+    #[test]
+    fn qr() {
+        let qr = A.qr();
+        assert_relative_eq!(qr.q() * qr.r(), A, epsilon = 1e-5);
+        assert_relative_eq!(qr.q().transpose() * qr.q(), Matrix::identity(), epsilon = 1e-6);
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(qr.r()[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let b = [5.0, -2.0, 9.0];
+        let expected = [1.0, 1.0, 2.0];
+        assert_relative_eq!(A.lu().solve(&b).unwrap()[..], expected[..], epsilon = 1e-5);
+        assert_relative_eq!(A.qr().solve(&b).unwrap()[..], expected[..], epsilon = 1e-5);
+        assert_relative_eq!(A.solve(&b).unwrap()[..], expected[..], epsilon = 1e-5);
+    }
+
+    #[test]
+    fn solve_named() {
+        // Named matrices multiply row vectors on the left, so `solve` undoes `x * matrix`.
+        let matrix = Matrix3x3::from(A);
+        let x = Vector3::new(0.5, -1.0, 3.0);
+        assert_relative_eq!(matrix.solve(&(x * matrix)).unwrap(), x, epsilon = 1e-5);
+        let b = Vector3::new(1.0, 2.0, 3.0);
+        assert_relative_eq!(matrix.solve(&b).unwrap(), b * matrix.inverse(), epsilon = 1e-5);
+
+        let matrix = Matrix4x4 {
+            c00: 4.0, c10: 1.0, c20: 0.0, c30: 2.0,
+            c01: 1.0, c11: 3.0, c21: -1.0, c31: 0.0,
+            c02: 0.0, c12: 2.0, c22: 5.0, c32: 1.0,
+            c03: 1.0, c13: 0.0, c23: 1.0, c33: 6.0,
+        };
+        let x = Vector4::new(1.0, -2.0, 0.25, 4.0);
+        assert_relative_eq!(matrix.solve(&(x * matrix)).unwrap(), x, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn singular() {
+        let singular = Matrix::from_rows([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        assert!(singular.lu().is_singular());
+        assert_eq!(singular.solve(&[1.0, 2.0, 3.0]), Err(Error::SingularMatrix));
+        assert_eq!(singular.qr().solve(&[1.0, 2.0, 3.0]), Err(Error::SingularMatrix));
+        assert_eq!(Matrix3x3::from(singular).solve(&Vector3::new(1.0, 2.0, 3.0)), Err(Error::SingularMatrix));
+        assert_eq!(Matrix::<3, 3>::zero().solve(&[0.0; 3]), Err(Error::SingularMatrix));
+        assert_eq!(singular.condition_number(), f32::INFINITY);
+    }
+
+    #[test]
+    fn rank() {
+        let singular = Matrix::from_rows([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        assert_eq!(singular.rank(1e-5), 2);
+        assert_eq!(A.rank(1e-5), 3);
+        assert_eq!(Matrix::<3, 3>::zero().rank(1e-5), 0);
+        let outer = Matrix::<3, 1>::from_rows([[1.0], [2.0], [3.0]]) * Matrix::<1, 3>::from_rows([[4.0, 5.0, 6.0]]);
+        assert_eq!(outer.rank(1e-5), 1);
+        assert_eq!(Matrix4x4::from_nonuniform_scale(&Vector3::new(1.0, 0.0, 1.0)).rank(1e-6), 3);
+        assert_eq!(Matrix3x3::identity().rank(1e-6), 3);
+    }
+
+    #[test]
+    fn condition_number() {
+        assert_relative_eq!(Matrix::<3, 3>::identity().condition_number(), 1.0);
+        assert_relative_eq!(Matrix3x3::from(Matrix::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1e-3]])).condition_number(), 1e3, max_relative = 1e-5);
+        // The 1-norm condition number of the 4 x 4 Hilbert matrix is 28375.
+        assert_relative_eq!(hilbert().condition_number(), 28375.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn ill_conditioned() {
+        let hilbert = hilbert();
+        let x = [1.0, -1.0, 1.0, -1.0];
+        let mut b = [0.0; 4];
+        for (i, element) in b.iter_mut().enumerate() {
+            *element = (0..4).map(|j| hilbert[(i, j)] * x[j]).sum();
+        }
+        assert_relative_eq!(hilbert.lu().solve(&b).unwrap()[..], x[..], epsilon = 1e-10);
+        assert_relative_eq!(hilbert.qr().solve(&b).unwrap()[..], x[..], epsilon = 1e-10);
+        assert_eq!(hilbert.rank(1e-12), 4);
+
+        // In single precision the error grows with the condition number, but stays bounded.
+        let single = hilbert.to_f32();
+        let b_single = [b[0] as f32, b[1] as f32, b[2] as f32, b[3] as f32];
+        let solved = single.solve(&b_single).unwrap();
+        for (solved, expected) in solved.iter().zip(x.iter()) {
+            assert!((*solved as f64 - expected).abs() < 28375.0 * f32::EPSILON as f64 * 4.0);
+        }
+        let matrix = DMatrix4x4::from(hilbert);
+        let x = DVector4::new(1.0, -1.0, 1.0, -1.0);
+        assert_relative_eq!(matrix.solve(&(x * matrix)).unwrap(), x, epsilon = 1e-10);
+
+        // Nearly parallel rows are still full rank with a small tolerance, but not a loose one.
+        let nearly_singular = Matrix::from_rows([
+            [1.0, 1.0, 0.0],
+            [1.0, 1.0001, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        assert_eq!(nearly_singular.rank(1e-6), 3);
+        assert_eq!(nearly_singular.rank(1e-3), 2);
+        assert!(nearly_singular.condition_number() > 1e4);
+        let solved = nearly_singular.qr().solve(&[2.0, 2.0001, 1.0]).unwrap();
+        assert_relative_eq!(solved[..], [1.0, 1.0, 1.0][..], epsilon = 1e-2);
+    }
+}
+
+mod spectral {
+    use ezcgmath::Degrees;
+    use ezcgmath::matrix::{Matrix, Matrix3x3, DMatrix3x3};
+    use ezcgmath::vector::Vector3;
+
+    const A: Matrix3x3 = Matrix3x3 {
+        c00: 2.0, c10: -1.0, c20: 0.5,
+        c01: 0.3, c11: 1.5, c21: 2.0,
+        c02: -1.0, c12: 0.2, c22: 0.7,
+    };
+
+    fn diagonal(values: [f32; 3]) -> Matrix3x3 {
+        Matrix3x3 { c00: values[0], c11: values[1], c22: values[2], ..Matrix3x3::default() }
+    }
+
+    fn rotation() -> Matrix3x3 {
+        let mut axis = Vector3::new(1.0, 2.0, 3.0);
+        axis.normalize();
+        Matrix3x3::from_axis_angle(&axis, Degrees(50.0))
+    }
+
+    fn assert_orthogonal(m: &Matrix3x3) {
+        assert_relative_eq!(*m * m.transpose(), Matrix3x3::identity(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        // An inertia tensor with principal moments of 1, 3 and 5, in a rotated frame.
+        let inertia = rotation().transpose() * diagonal([1.0, 5.0, 3.0]) * rotation();
+        let eigen = inertia.symmetric_eigen();
+        assert_relative_eq!(eigen.eigenvalues[..], [5.0, 3.0, 1.0][..], epsilon = 1e-5);
+        for (value, vector) in eigen.eigenvalues.iter().zip(eigen.eigenvectors.iter()) {
+            assert_relative_eq!(*vector * inertia, *vector * *value, epsilon = 1e-5);
+            assert_relative_eq!(vector.length(), 1.0, epsilon = 1e-6);
+        }
+        let basis = Matrix3x3::from_basis(&eigen.eigenvectors[0], &eigen.eigenvectors[1], &eigen.eigenvectors[2]);
+        assert_orthogonal(&basis);
+        assert_relative_eq!(basis.determinant(), 1.0, epsilon = 1e-5);
+        // The principal axes of the rotated frame are its second, third and first rows.
+        assert_relative_eq!(eigen.eigenvectors[0].dot(&(Vector3::unit_y() * rotation())).abs(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn symmetric_eigen_repeated() {
+        let eigen = (Matrix3x3::identity() * 2.0).symmetric_eigen();
+        assert_eq!(eigen.eigenvalues, [2.0, 2.0, 2.0]);
+        let eigen = diagonal([1.0, 1.0, 4.0]).symmetric_eigen();
+        assert_eq!(eigen.eigenvalues, [4.0, 1.0, 1.0]);
+        assert_eq!(eigen.eigenvectors[0], Vector3::unit_z());
+    }
+
+    #[test]
+    fn symmetric_eigen_generic() {
+        let m = Matrix::from_rows([
+            [4.0, 1.0, 0.5, 0.0],
+            [1.0, 3.0, 0.0, 0.2],
+            [0.5, 0.0, 2.0, 0.1],
+            [0.0, 0.2, 0.1, 1.0],
+        ]);
+        let (eigenvalues, eigenvectors) = m.symmetric_eigen();
+        assert!(eigenvalues.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_relative_eq!(eigenvalues.iter().sum::<f32>(), 10.0, epsilon = 1e-5);
+        assert_relative_eq!(eigenvectors.transpose() * eigenvectors, Matrix::identity(), epsilon = 1e-5);
+        assert_relative_eq!(m * eigenvectors, eigenvectors * diagonal4(eigenvalues), epsilon = 1e-5);
+    }
+
+    fn diagonal4(values: [f32; 4]) -> Matrix<4, 4> {
+        let mut result = Matrix::zero();
+        for (i, value) in values.iter().enumerate() {
+            result.rows[i][i] = *value;
+        }
+        result
+    }
+
+    #[test]
+    fn svd() {
+        for m in [A, A.transpose() * -1.0, rotation() * diagonal([3.0, 0.5, 2.0])].iter() {
+            let svd = m.svd();
+            assert_relative_eq!(svd.u * diagonal(svd.singular_values) * svd.v.transpose(), *m, epsilon = 1e-5);
+            assert_orthogonal(&svd.u);
+            assert_orthogonal(&svd.v);
+            assert!(svd.singular_values[0] >= svd.singular_values[1]);
+            assert!(svd.singular_values[1] >= svd.singular_values[2]);
+            assert!(svd.singular_values[2] >= 0.0);
+        }
+        let svd = (rotation() * diagonal([3.0, 0.5, 2.0])).svd();
+        assert_relative_eq!(svd.singular_values[..], [3.0, 2.0, 0.5][..], epsilon = 1e-5);
+    }
+
+    #[test]
+    fn svd_singular() {
+        // Every row lies in the same plane, so one singular value is zero.
+        let flat = Matrix3x3 {
+            c00: 1.0, c10: 2.0, c20: 0.0,
+            c01: 3.0, c11: -1.0, c21: 0.0,
+            c02: 2.0, c12: 1.0, c22: 0.0,
+        } * rotation();
+        let svd = flat.svd();
+        assert_relative_eq!(svd.singular_values[2], 0.0, epsilon = 1e-5);
+        assert_relative_eq!(svd.u * diagonal(svd.singular_values) * svd.v.transpose(), flat, epsilon = 1e-5);
+        assert_orthogonal(&svd.u);
+
+        let svd = Matrix3x3::default().svd();
+        assert_eq!(svd.singular_values, [0.0, 0.0, 0.0]);
+        assert_orthogonal(&svd.u);
+    }
+
+    #[test]
+    fn polar_decomposition() {
+        let stretch = rotation().transpose() * diagonal([2.0, 0.5, 1.5]) * rotation();
+        let spin = Matrix3x3::from_rotation_y(Degrees(-70.0));
+        let (rotation, recovered_stretch) = (stretch * spin).polar_decomposition();
+        assert_relative_eq!(rotation, spin, epsilon = 1e-5);
+        assert_relative_eq!(recovered_stretch, stretch, epsilon = 1e-5);
+
+        let (rotation, stretch) = A.polar_decomposition();
+        assert_relative_eq!(stretch * rotation, A, epsilon = 1e-5);
+        assert_relative_eq!(stretch, stretch.transpose(), epsilon = 1e-5);
+        assert_orthogonal(&rotation);
+        assert_relative_eq!(rotation.determinant(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn polar_decomposition_reflection() {
+        let mirrored = Matrix3x3::from_reflection(&Vector3::unit_x()) * rotation();
+        let (rotation, stretch) = mirrored.polar_decomposition();
+        assert_relative_eq!(rotation.determinant(), 1.0, epsilon = 1e-5);
+        assert_relative_eq!(stretch.determinant(), -1.0, epsilon = 1e-5);
+        assert_relative_eq!(stretch * rotation, mirrored, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn nearest_rotation() {
+        // Accumulate many small rotations, with a little error in each, as a long simulation would.
+        let step = rotation() * 1.0001 + Matrix3x3 { c10: 1e-4, ..Matrix3x3::default() };
+        let mut drifting = Matrix3x3::identity();
+        for _ in 0..200 {
+            drifting *= step;
+        }
+        assert!((drifting.determinant() - 1.0).abs() > 1e-2);
+        let repaired = drifting.nearest_rotation();
+        assert_orthogonal(&repaired);
+        assert_relative_eq!(repaired.determinant(), 1.0, epsilon = 1e-5);
+        assert_relative_eq!(rotation().nearest_rotation(), rotation(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn double_precision() {
+        let m = A.to_f64();
+        let svd = m.svd();
+        let sigma = DMatrix3x3 { c00: svd.singular_values[0], c11: svd.singular_values[1], c22: svd.singular_values[2], ..DMatrix3x3::default() };
+        assert_relative_eq!(svd.u * sigma * svd.v.transpose(), m, epsilon = 1e-12);
+        let (rotation, stretch) = m.polar_decomposition();
+        assert_relative_eq!(stretch * rotation, m, epsilon = 1e-12);
+        let eigen = (m * m.transpose()).symmetric_eigen();
+        assert_relative_eq!(eigen.eigenvalues[0], svd.singular_values[0] * svd.singular_values[0], epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "mint")]
+
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::prelude::*;
+use ezcgmath::matrix::{Matrix2x2, Matrix3x3};
+use ezcgmath::vector::{Vector2, Vector4};
+
+const M: Matrix4x4 = Matrix4x4 {
+    c00:  1.0, c10:  2.0, c20:  3.0, c30:  4.0,
+    c01:  5.0, c11:  6.0, c21:  7.0, c31:  8.0,
+    c02:  9.0, c12: 10.0, c22: 11.0, c32: 12.0,
+    c03: 13.0, c13: 14.0, c23: 15.0, c33: 16.0,
+};
+
+fn column_transform(m: &mint::ColumnMatrix4<f32>, v: mint::Vector4<f32>) -> Vector4 {
+    Vector4::new(
+        m.x.x * v.x + m.y.x * v.y + m.z.x * v.z + m.w.x * v.w,
+        m.x.y * v.x + m.y.y * v.y + m.z.y * v.z + m.w.y * v.w,
+        m.x.z * v.x + m.y.z * v.y + m.z.z * v.z + m.w.z * v.w,
+        m.x.w * v.x + m.y.w * v.y + m.z.w * v.z + m.w.w * v.w,
+    )
+}
+
+#[test]
+fn vectors() {
+    let vec2: mint::Vector2<f32> = Vector2::new(1.0, 2.0).into();
+    assert_eq!(vec2, mint::Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Vector2::from(vec2), Vector2::new(1.0, 2.0));
+
+    let vec3: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(vec3, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    assert_eq!(Vector3::from(vec3), Vector3::new(1.0, 2.0, 3.0));
+
+    let vec4: mint::Vector4<f32> = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(vec4, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+    assert_eq!(Vector4::from(vec4), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn matrix4x4_translation_in_last_column() {
+    let translation = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    let column: mint::ColumnMatrix4<f32> = translation.into();
+    assert_eq!(column.w, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+    let row: mint::RowMatrix4<f32> = translation.into();
+    assert_eq!(row.x, mint::Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 });
+}
+
+#[test]
+fn matrix4x4_same_transformation() {
+    let column: mint::ColumnMatrix4<f32> = M.into();
+    let v = Vector4::new(1.0, -2.0, 3.0, 1.0);
+    assert_ulps_eq!(column_transform(&column, v.into()), v * M);
+}
+
+#[test]
+fn matrix_round_trips() {
+    let column: mint::ColumnMatrix4<f32> = M.into();
+    assert_eq!(Matrix4x4::from(column), M);
+    let row: mint::RowMatrix4<f32> = M.into();
+    assert_eq!(Matrix4x4::from(row), M);
+
+    let m3 = Matrix3x3 {
+        c00: 1.0, c10: 2.0, c20: 3.0,
+        c01: 4.0, c11: 5.0, c21: 6.0,
+        c02: 7.0, c12: 8.0, c22: 9.0,
+    };
+    let column: mint::ColumnMatrix3<f32> = m3.into();
+    assert_eq!(column.x, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    assert_eq!(Matrix3x3::from(column), m3);
+    let row: mint::RowMatrix3<f32> = m3.into();
+    assert_eq!(row.x, mint::Vector3 { x: 1.0, y: 4.0, z: 7.0 });
+    assert_eq!(Matrix3x3::from(row), m3);
+
+    let m2 = Matrix2x2 {
+        c00: 1.0, c10: 2.0,
+        c01: 3.0, c11: 4.0,
+    };
+    let column: mint::ColumnMatrix2<f32> = m2.into();
+    assert_eq!(column.x, mint::Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Matrix2x2::from(column), m2);
+    let row: mint::RowMatrix2<f32> = m2.into();
+    assert_eq!(row.x, mint::Vector2 { x: 1.0, y: 3.0 });
+    assert_eq!(Matrix2x2::from(row), m2);
+}
+
+#[test]
+fn quaternion_same_rotation() {
+    let rotation = Quaternion::from_axis_angle(&(Vector3::new(1.0, 2.0, 3.0) / 14.0_f32.sqrt()), Degrees(70.0));
+    let converted: mint::Quaternion<f32> = rotation.into();
+    assert_eq!(Quaternion::from(converted), rotation);
+
+    // Rotate with v' = q * v * q^-1, as a column-vector library would.
+    let u = Vector3::new(converted.v.x, converted.v.y, converted.v.z);
+    let v = Vector3::new(4.0, -5.0, 6.0);
+    let t = u.cross(&v) * 2.0;
+    let rotated = v + t * converted.s + u.cross(&t);
+    assert_relative_eq!(rotated, v * rotation, epsilon = 1e-5);
+
+    let column: mint::ColumnMatrix4<f32> = Matrix4x4::from(rotation).into();
+    let transformed: Vector3 = column_transform(&column, Vector4::from(v).into()).into();
+    assert_relative_eq!(transformed, rotated, epsilon = 1e-5);
+}
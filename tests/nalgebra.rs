@@ -0,0 +1,78 @@
+#![cfg(feature = "nalgebra")]
+
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::prelude::*;
+use ezcgmath::matrix::{Matrix2x2, Matrix3x3};
+use ezcgmath::vector::{Vector2, Vector4};
+
+const M: Matrix4x4 = Matrix4x4 {
+    c00: 2.0, c10: 0.5, c20: 1.0, c30: 0.0,
+    c01: 1.0, c11: 3.0, c21: 0.0, c31: 0.0,
+    c02: 0.0, c12: 1.0, c22: 4.0, c32: 0.0,
+    c03: 5.0, c13: 6.0, c23: 7.0, c33: 1.0,
+};
+
+#[test]
+fn round_trips() {
+    assert_eq!(Vector2::from(nalgebra::Vector2::from(Vector2::new(1.0, 2.0))), Vector2::new(1.0, 2.0));
+    assert_eq!(Vector3::from(nalgebra::Vector3::from(Vector3::new(1.0, 2.0, 3.0))), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector4::from(nalgebra::Vector4::from(Vector4::new(1.0, 2.0, 3.0, 4.0))), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    let rotation = Quaternion::from_euler(Degrees(10.0), Degrees(20.0), Degrees(30.0));
+    assert_eq!(Quaternion::from(nalgebra::Quaternion::from(rotation)), rotation);
+    assert_relative_eq!(Quaternion::from(nalgebra::UnitQuaternion::from(rotation)), rotation, epsilon = 1e-6);
+    let m2 = Matrix2x2 { c00: 1.0, c10: 2.0, c01: 3.0, c11: 4.0 };
+    assert_eq!(Matrix2x2::from(nalgebra::Matrix2::from(m2)), m2);
+    let m3 = Matrix3x3 {
+        c00: 1.0, c10: 2.0, c20: 3.0,
+        c01: 4.0, c11: 5.0, c21: 6.0,
+        c02: 7.0, c12: 8.0, c22: 9.0,
+    };
+    assert_eq!(Matrix3x3::from(nalgebra::Matrix3::from(m3)), m3);
+    assert_eq!(Matrix4x4::from(nalgebra::Matrix4::from(M)), M);
+}
+
+#[test]
+fn transform_point() {
+    let point = Vector3::new(1.0, -2.0, 3.0);
+    let expected = nalgebra::Matrix4::from(M).transform_point(&nalgebra::Point3::new(1.0, -2.0, 3.0));
+    assert_relative_eq!(M.transform_point3(&point), Vector3::from(expected.coords), epsilon = 1e-5);
+
+    let translation = Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(nalgebra::Matrix4::from(translation), nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn inverse() {
+    let expected = Matrix4x4::from(nalgebra::Matrix4::from(M).try_inverse().unwrap());
+    assert_relative_eq!(M.inverse(), expected, epsilon = 1e-5);
+
+    let m3 = Matrix3x3 {
+        c00: 2.0, c10: 1.0, c20: 0.0,
+        c01: 0.5, c11: 3.0, c21: 1.0,
+        c02: 1.0, c12: 0.0, c22: 4.0,
+    };
+    let expected = Matrix3x3::from(nalgebra::Matrix3::from(m3).try_inverse().unwrap());
+    assert_relative_eq!(m3.inverse(), expected, epsilon = 1e-5);
+}
+
+#[test]
+fn quaternion_rotation() {
+    let rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let expected = nalgebra::UnitQuaternion::from(rotation) * nalgebra::Vector3::from(vector);
+    assert_relative_eq!(vector * rotation, Vector3::from(expected), epsilon = 1e-5);
+
+    let expected = Matrix4x4::from(nalgebra::UnitQuaternion::from(rotation).to_homogeneous());
+    assert_relative_eq!(Matrix4x4::from(rotation), expected, epsilon = 1e-6);
+}
+
+#[test]
+fn from_euler() {
+    let (x, y, z) = (Degrees(15.0), Degrees(-40.0), Degrees(75.0));
+    let euler = nalgebra::UnitQuaternion::from_euler_angles(Radians::from(x).0, Radians::from(y).0, Radians::from(z).0);
+    // ezcgmath applies rotations to row vectors, so its euler rotation is the inverse of the column-vector one.
+    let expected = Quaternion::from(euler.inverse());
+    assert_relative_eq!(Quaternion::from_euler(x, y, z), expected, epsilon = 1e-6);
+}
@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate approx;
+
+mod point2 {
+    use ezcgmath::point::Point2;
+    use ezcgmath::vector::Vector2;
+
+    const A: Point2 = Point2::new(1.0, 2.0);
+    const B: Point2 = Point2::new(4.0, 6.0);
+
+    #[test]
+    fn subtract_point() {
+        assert_ulps_eq!(B - A, Vector2::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn add_vector() {
+        let result = Point2::new(4.0, 6.0);
+        assert_ulps_eq!(A + Vector2::new(3.0, 4.0), result);
+        let mut point = A;
+        point += Vector2::new(3.0, 4.0);
+        assert_ulps_eq!(point, result);
+    }
+
+    #[test]
+    fn subtract_vector() {
+        let result = Point2::new(1.0, 2.0);
+        assert_ulps_eq!(B - Vector2::new(3.0, 4.0), result);
+        let mut point = B;
+        point -= Vector2::new(3.0, 4.0);
+        assert_ulps_eq!(point, result);
+    }
+
+    #[test]
+    fn distance() {
+        assert_ulps_eq!(A.distance(&B), 5.0);
+    }
+}
+
+mod point3 {
+    use ezcgmath::Degrees;
+    use ezcgmath::matrix::{Matrix3x3, Matrix4x4};
+    use ezcgmath::point::{DPoint3, Point3};
+    use ezcgmath::quaternion::Quaternion;
+    use ezcgmath::vector::Vector3;
+
+    const A: Point3 = Point3::new(1.0, 2.0, 3.0);
+    const B: Point3 = Point3::new(3.0, 5.0, 9.0);
+
+    #[test]
+    fn subtract_point() {
+        assert_ulps_eq!(B - A, Vector3::new(2.0, 3.0, 6.0));
+    }
+
+    #[test]
+    fn add_and_subtract_vector() {
+        assert_ulps_eq!(A + Vector3::new(2.0, 3.0, 6.0), B);
+        assert_ulps_eq!(B - Vector3::new(2.0, 3.0, 6.0), A);
+        let mut point = A;
+        point += Vector3::new(2.0, 3.0, 6.0);
+        point -= Vector3::new(1.0, 1.0, 1.0);
+        assert_ulps_eq!(point, Point3::new(2.0, 4.0, 8.0));
+    }
+
+    #[test]
+    fn distance() {
+        assert_ulps_eq!(A.distance(&B), 7.0);
+        assert_ulps_eq!(Point3::origin().distance(&A), Vector3::from(A).length());
+    }
+
+    #[test]
+    fn multiply_matrix4x4() {
+        let translation = Matrix4x4::from_translation(&Vector3::new(10.0, 20.0, 30.0));
+        assert_ulps_eq!(A * translation, Point3::new(11.0, 22.0, 33.0));
+        assert_ulps_eq!(translation.transform_vector3(&(B - A)), B - A);
+
+        let mut point = A;
+        point *= translation;
+        assert_ulps_eq!(point, Point3::new(11.0, 22.0, 33.0));
+    }
+
+    #[test]
+    fn multiply_rotation() {
+        let rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
+        assert_relative_eq!(A * rotation, Point3::from(Vector3::from(A) * rotation));
+        assert_ulps_eq!(A * Matrix3x3::identity(), A);
+    }
+
+    #[test]
+    fn precision_conversion() {
+        assert_eq!(A.to_f64(), DPoint3::new(1.0, 2.0, 3.0));
+        assert_eq!(DPoint3::new(1.0, 2.0, 3.0).to_f32(), A);
+    }
+}
@@ -1,6 +1,8 @@
 #[macro_use]
 extern crate approx;
 
+use std::f32::consts::FRAC_1_SQRT_2;
+
 use ezcgmath::Degrees;
 use ezcgmath::quaternion::Quaternion;
 use ezcgmath::vector::Vector3;
@@ -8,12 +10,11 @@ use ezcgmath::vector::Vector3;
 #[test]
 pub fn from_axis_angle() {
     let axis_angle_rotation = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
-    assert_ulps_eq!(axis_angle_rotation, Quaternion { x: 0.0, y: 0.7071067811865474, z: 0.0, w: 0.7071067811865474 })
+    assert_ulps_eq!(axis_angle_rotation, Quaternion { x: 0.0, y: FRAC_1_SQRT_2, z: 0.0, w: FRAC_1_SQRT_2 })
 }
 
 #[test]
 pub fn from_look_at() {
-    // These tests seem ok, but they're not fully covering the from_look_at method.
     let default_orientation = Quaternion::from_look_at(&Vector3::unit_z(), &Vector3::unit_y());
     assert_ulps_eq!(default_orientation, Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 });
 
@@ -21,23 +22,58 @@ pub fn from_look_at() {
     assert_ulps_eq!(negative_up_right_forward, Quaternion { x: -0.5, y: -0.5, z: 0.5, w: 0.5 });
 
     let larger_right_vector = Quaternion::from_look_at(&Vector3::new(0.0, 1.0, 1.0), &Vector3::new(0.0, 1.0, -1.0));
-    assert_ulps_eq!(larger_right_vector, Quaternion { x: -0.3826834290674337, y: 0.0, z: 0.0, w: 0.9238795338772207 });
+    assert_ulps_eq!(larger_right_vector, Quaternion { x: -0.382_683_43, y: 0.0, z: 0.0, w: 0.923_879_5 });
 
     let larger_up_vector = Quaternion::from_look_at(&Vector3::new(-1.0, 0.0, 1.0), &Vector3::new(-1.0, 0.0, -1.0));
-    assert_ulps_eq!(larger_up_vector, Quaternion { x: -0.2705980477413035, y: -0.2705980477413035, z: 0.6532814834040493, w: 0.6532814834040493 });
+    assert_ulps_eq!(larger_up_vector, Quaternion { x: -0.270_598_05, y: -0.270_598_05, z: 0.653_281_5, w: 0.653_281_5 });
 
     let larger_forward_vector = Quaternion::from_look_at(&Vector3::new(0.0, 0.0, 1.0), &Vector3::new(-1.0, 1.0, 0.0));
-    assert_ulps_eq!(larger_forward_vector, Quaternion { x: 0.0, y: 0.0, z: 0.3826834290674337, w: 0.9238795338772207 });
+    assert_ulps_eq!(larger_forward_vector, Quaternion { x: 0.0, y: 0.0, z: 0.382_683_43, w: 0.923_879_5 });
+
+    // Turning more than 120 degrees makes the trace negative, and the largest diagonal element picks the branch.
+    let sqrt_3 = 3.0f32.sqrt();
+    let (sin_75, cos_75) = (0.965_925_8, 0.258_819_04);
+    let largest_right = Quaternion::from_look_at(&Vector3::new(0.0, 1.0, -sqrt_3), &Vector3::new(0.0, -sqrt_3, -1.0));
+    assert_relative_eq!(largest_right, Quaternion { x: sin_75, y: 0.0, z: 0.0, w: -cos_75 }, epsilon = 1e-6);
+
+    let largest_up = Quaternion::from_look_at(&Vector3::new(-1.0, 0.0, -sqrt_3), &Vector3::unit_y());
+    assert_relative_eq!(largest_up, Quaternion { x: 0.0, y: sin_75, z: 0.0, w: -cos_75 }, epsilon = 1e-6);
+
+    let largest_forward = Quaternion::from_look_at(&Vector3::unit_z(), &Vector3::new(1.0, -sqrt_3, 0.0));
+    assert_relative_eq!(largest_forward, Quaternion { x: 0.0, y: 0.0, z: sin_75, w: -cos_75 }, epsilon = 1e-6);
+
+    let straight_down = Quaternion::from_look_at(&-Vector3::unit_y(), &Vector3::unit_y());
+    assert_relative_eq!(straight_down, Quaternion { x: FRAC_1_SQRT_2, y: 0.0, z: 0.0, w: FRAC_1_SQRT_2 }, epsilon = 1e-6);
+}
+
+#[test]
+fn from_rotation_arc() {
+    let from = Vector3::new(1.0, 2.0, 3.0);
+    let to = Vector3::new(-2.0, 0.5, 1.0);
+    let mut expected = to;
+    expected.normalize();
+    expected *= from.length();
+    assert_relative_eq!(from * Quaternion::from_rotation_arc(&from, &to), expected, epsilon = 1e-5);
+
+    let quarter_turn = Quaternion::from_rotation_arc(&Vector3::unit_z(), &Vector3::unit_x());
+    assert_relative_eq!(quarter_turn, Quaternion { x: 0.0, y: -FRAC_1_SQRT_2, z: 0.0, w: FRAC_1_SQRT_2 });
+
+    assert_ulps_eq!(Quaternion::from_rotation_arc(&from, &(from * 2.0)), Quaternion::identity());
+
+    let opposite = Quaternion::from_rotation_arc(&from, &-from);
+    assert_relative_eq!(opposite.w, 0.0);
+    assert_relative_eq!(from * opposite, -from, epsilon = 1e-5);
+    assert_relative_eq!(Vector3::unit_y() * Quaternion::from_rotation_arc(&Vector3::unit_y(), &-Vector3::unit_y()), -Vector3::unit_y());
 }
 
 #[test]
 fn from_euler() {
     let from_euler = Quaternion::from_euler(Degrees(90.0), Degrees(0.0), Degrees(0.0));
-    assert_ulps_eq!(from_euler, Quaternion { x: 0.7071068, y: 0.0, z: 0.0, w: 0.7071068 });
+    assert_ulps_eq!(from_euler, Quaternion { x: FRAC_1_SQRT_2, y: 0.0, z: 0.0, w: FRAC_1_SQRT_2 });
     let from_euler = Quaternion::from_euler(Degrees(0.0), Degrees(90.0), Degrees(0.0));
-    assert_ulps_eq!(from_euler, Quaternion { x: 0.0, y: 0.7071068, z: 0.0, w: 0.7071068 });
+    assert_ulps_eq!(from_euler, Quaternion { x: 0.0, y: FRAC_1_SQRT_2, z: 0.0, w: FRAC_1_SQRT_2 });
     let from_euler = Quaternion::from_euler(Degrees(0.0), Degrees(0.0), Degrees(90.0));
-    assert_ulps_eq!(from_euler, Quaternion { x: 0.0, y: 0.0, z: 0.7071068, w: 0.7071068 });
+    assert_ulps_eq!(from_euler, Quaternion { x: 0.0, y: 0.0, z: FRAC_1_SQRT_2, w: FRAC_1_SQRT_2 });
 }
 
 #[test]
@@ -46,3 +82,231 @@ fn multiply_quaternion() {
     let b = Quaternion { x: 2.0, y: 4.0, z: 6.0, w: 8.0 };
     assert_ulps_eq!(a * b, Quaternion { x: 16.0, y: 32.0, z: 48.0, w: 4.0 });
 }
+
+#[test]
+fn double_precision() {
+    use ezcgmath::quaternion::DQuaternion;
+    use ezcgmath::vector::DVector3;
+
+    let single = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0));
+    let double = DQuaternion::from_axis_angle(&DVector3::unit_y(), Degrees(90.0));
+    assert_relative_eq!(double.y, std::f64::consts::FRAC_1_SQRT_2, epsilon = 1e-12);
+    assert_ulps_eq!(double.to_f32(), single);
+    assert_relative_eq!(single.to_f64(), double, epsilon = 1e-7);
+}
+
+#[test]
+fn try_normalize() {
+    let mut zero = Quaternion::default();
+    assert_eq!(zero.try_normalize(1e-6), Err(ezcgmath::Error::ZeroLength));
+    assert_eq!(zero, Quaternion::default());
+
+    let scaled = Quaternion { x: 0.0, y: 2.0, z: 0.0, w: 2.0 };
+    assert_ulps_eq!(scaled.checked_normalize(1e-6).unwrap(), Quaternion { x: 0.0, y: FRAC_1_SQRT_2, z: 0.0, w: FRAC_1_SQRT_2 });
+}
+
+#[test]
+fn swing_twist() {
+    let twist = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(30.0));
+    let swing = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(40.0));
+    let rotation = twist * swing;
+    let (found_swing, found_twist) = rotation.swing_twist(&Vector3::unit_y());
+    assert_relative_eq!(found_swing, swing, epsilon = 1e-6);
+    assert_relative_eq!(found_twist, twist, epsilon = 1e-6);
+    assert_relative_eq!(Vector3::unit_y() * found_swing, Vector3::unit_y() * rotation, epsilon = 1e-6);
+
+    let negative = Quaternion { x: -rotation.x, y: -rotation.y, z: -rotation.z, w: -rotation.w };
+    let (negative_swing, negative_twist) = negative.swing_twist(&Vector3::unit_y());
+    assert_relative_eq!(negative_twist, twist, epsilon = 1e-6);
+    assert_relative_eq!(negative_twist * negative_swing, negative, epsilon = 1e-6);
+
+    let (pure_swing, pure_twist) = twist.swing_twist(&Vector3::unit_y());
+    assert_relative_eq!(pure_swing, Quaternion::identity(), epsilon = 1e-6);
+    assert_relative_eq!(pure_twist, twist, epsilon = 1e-6);
+
+    let half_turn = Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(180.0));
+    let (half_turn_swing, half_turn_twist) = half_turn.swing_twist(&Vector3::unit_y());
+    assert_ulps_eq!(half_turn_twist, Quaternion::identity());
+    assert_ulps_eq!(half_turn_swing, half_turn);
+}
+
+#[test]
+fn clamp_twist() {
+    let swing = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(20.0));
+    let twist = |angle| Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(angle));
+    let clamp = |rotation: Quaternion| rotation.clamp_twist(&Vector3::unit_y(), Degrees(-45.0), Degrees(45.0));
+    assert_relative_eq!(clamp(twist(70.0) * swing), twist(45.0) * swing, epsilon = 1e-6);
+    assert_relative_eq!(clamp(twist(-80.0) * swing), twist(-45.0) * swing, epsilon = 1e-6);
+    assert_eq!(clamp(twist(30.0) * swing), twist(30.0) * swing);
+}
+
+#[test]
+fn clamp_swing_cone() {
+    let twist = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(10.0));
+    let swing = |angle| Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(angle));
+    let clamp = |rotation: Quaternion| rotation.clamp_swing_cone(&Vector3::unit_y(), Degrees(45.0));
+    assert_relative_eq!(clamp(twist * swing(60.0)), twist * swing(45.0), epsilon = 1e-6);
+    assert_relative_eq!(clamp(twist * swing(-170.0)), twist * swing(-45.0), epsilon = 1e-6);
+    assert_eq!(clamp(twist * swing(30.0)), twist * swing(30.0));
+}
+
+#[test]
+fn clamp_swing_ellipse() {
+    let clamp = |rotation: Quaternion| {
+        rotation.clamp_swing_ellipse(&Vector3::unit_y(), &Vector3::unit_x(), Degrees(60.0), Degrees(20.0))
+    };
+    let around_reference = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(50.0));
+    assert_eq!(clamp(around_reference), around_reference);
+    let around_other = Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(30.0));
+    assert_relative_eq!(clamp(around_other), Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(20.0)), epsilon = 1e-6);
+
+    let mut diagonal_axis = Vector3::new(1.0, 0.0, 1.0);
+    diagonal_axis.normalize();
+    let twist = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(15.0));
+    let clamped = clamp(twist * Quaternion::from_axis_angle(&diagonal_axis, Degrees(40.0)));
+    let (swing, clamped_twist) = clamped.swing_twist(&Vector3::unit_y());
+    assert_relative_eq!(clamped_twist, twist, epsilon = 1e-6);
+    let half_angle = swing.w.acos();
+    let angle_x = 2.0 * half_angle * swing.x / half_angle.sin();
+    let angle_z = 2.0 * half_angle * swing.z / half_angle.sin();
+    assert_relative_eq!(angle_x, angle_z, epsilon = 1e-6);
+    let distance = (angle_x / 60f32.to_radians()).powi(2) + (angle_z / 20f32.to_radians()).powi(2);
+    assert_relative_eq!(distance, 1.0, epsilon = 1e-5);
+}
+
+#[test]
+fn clamp_swing_ellipse_zero_limit() {
+    let around_reference = |angle| Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(angle));
+    let around_other = |angle| Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(angle));
+    let twist = Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(25.0));
+
+    // A zero limit around the other axis only allows swinging around the reference.
+    let clamp = |rotation: Quaternion| rotation.clamp_swing_ellipse(&Vector3::unit_y(), &Vector3::unit_x(), Degrees(30.0), Degrees(0.0));
+    assert_eq!(clamp(around_reference(10.0)), around_reference(10.0));
+    assert_relative_eq!(clamp(around_reference(50.0)), around_reference(30.0), epsilon = 1e-6);
+    assert_relative_eq!(clamp(around_other(20.0)), Quaternion::identity(), epsilon = 1e-6);
+    assert_relative_eq!(clamp(twist * around_other(20.0)), twist, epsilon = 1e-6);
+
+    // And the other way around.
+    let clamp = |rotation: Quaternion| rotation.clamp_swing_ellipse(&Vector3::unit_y(), &Vector3::unit_x(), Degrees(0.0), Degrees(30.0));
+    assert_eq!(clamp(around_other(-10.0)), around_other(-10.0));
+    assert_relative_eq!(clamp(around_other(-50.0)), around_other(-30.0), epsilon = 1e-6);
+    assert_relative_eq!(clamp(around_reference(20.0)), Quaternion::identity(), epsilon = 1e-6);
+
+    // With both limits at zero, only the twist is left.
+    let clamp = |rotation: Quaternion| rotation.clamp_swing_ellipse(&Vector3::unit_y(), &Vector3::unit_x(), Degrees(0.0), Degrees(0.0));
+    let rotation = twist * around_reference(40.0) * around_other(10.0);
+    assert_relative_eq!(clamp(rotation), rotation.swing_twist(&Vector3::unit_y()).1, epsilon = 1e-6);
+    assert_eq!(clamp(twist), twist);
+}
+
+#[test]
+fn weighted_average() {
+    let around_y = |angle| Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(angle));
+    assert_relative_eq!(Quaternion::weighted_average(&[(around_y(30.0), 2.0)]), around_y(30.0), epsilon = 1e-6);
+    assert_relative_eq!(Quaternion::weighted_average(&[(around_y(0.0), 1.0), (around_y(90.0), 1.0)]), around_y(45.0), epsilon = 1e-6);
+
+    let a = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(20.0));
+    let b = Quaternion::from_euler(Degrees(10.0), Degrees(-30.0), Degrees(5.0));
+    let c = Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(-40.0));
+    let negative_b = Quaternion { x: -b.x, y: -b.y, z: -b.z, w: -b.w };
+    let average = Quaternion::weighted_average(&[(a, 0.5), (b, 0.3), (c, 0.2)]);
+    assert_relative_eq!(Quaternion::weighted_average(&[(a, 0.5), (c, 0.2), (negative_b, 0.3)]), average, epsilon = 1e-6);
+    assert_relative_eq!(average.dot(&average), 1.0, epsilon = 1e-6);
+    // For rotations within a few tens of degrees, the normalized sum is close to the true average.
+    let nlerp = Quaternion::weighted_nlerp(&[(a, 0.5), (b, 0.3), (c, 0.2)]);
+    assert_relative_eq!(average, nlerp, epsilon = 5e-3);
+
+    assert_eq!(Quaternion::weighted_average(&[]), Quaternion::identity());
+    assert_eq!(Quaternion::weighted_average(&[(a, 0.0)]), Quaternion::identity());
+}
+
+#[test]
+fn weighted_nlerp() {
+    let around_y = |angle| Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(angle));
+    assert_relative_eq!(Quaternion::weighted_nlerp(&[(around_y(0.0), 1.0), (around_y(90.0), 1.0)]), around_y(45.0), epsilon = 1e-6);
+
+    // Half a turn past 180 degrees is the negated quaternion, and must still blend the short way.
+    let flipped = around_y(-60.0);
+    let flipped = Quaternion { x: -flipped.x, y: -flipped.y, z: -flipped.z, w: -flipped.w };
+    assert_relative_eq!(Quaternion::weighted_nlerp(&[(around_y(60.0), 1.0), (flipped, 1.0)]), Quaternion::identity(), epsilon = 1e-6);
+
+    assert_eq!(Quaternion::weighted_nlerp(&[]), Quaternion::identity());
+    assert_eq!(Quaternion::weighted_nlerp(&[(around_y(30.0), 0.0)]), Quaternion::identity());
+    assert_eq!(Quaternion::weighted_nlerp(&[(around_y(30.0), -1.0)]), Quaternion::identity());
+    assert_eq!(Quaternion::weighted_nlerp(&[(around_y(30.0), 1.0), (around_y(80.0), -1.0)]), Quaternion::identity());
+}
+
+#[test]
+fn scaled_axis() {
+    let rotation = Quaternion::from_scaled_axis(&Vector3::new(0.0, std::f32::consts::FRAC_PI_2, 0.0));
+    assert_relative_eq!(rotation, Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0)), epsilon = 1e-6);
+    assert_eq!(Quaternion::from_scaled_axis(&Vector3::default()), Quaternion::identity());
+
+    let vector = Vector3::new(0.3, -1.2, 0.5);
+    assert_relative_eq!(Quaternion::from_scaled_axis(&vector).to_scaled_axis(), vector, epsilon = 1e-6);
+    let tiny = Vector3::new(1e-5, 0.0, -2e-5);
+    assert_relative_eq!(Quaternion::from_scaled_axis(&tiny).to_scaled_axis(), tiny, epsilon = 1e-9);
+    // Three quarters of a turn is the same as a quarter turn the other way.
+    let long_way = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(270.0));
+    assert_relative_eq!(long_way.to_scaled_axis(), Vector3::new(-std::f32::consts::FRAC_PI_2, 0.0, 0.0), epsilon = 1e-6);
+}
+
+#[test]
+fn integrate() {
+    let orientation = Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(30.0));
+    let angular_velocity = Vector3::new(0.0, std::f32::consts::PI, 0.0);
+    let turned = orientation.integrate(&angular_velocity, 0.5);
+    assert_relative_eq!(turned, orientation * Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(90.0)), epsilon = 1e-6);
+
+    // Many small steps agree with one large one, and stay normalized.
+    let mut exact = orientation;
+    let mut first_order = orientation;
+    for _ in 0..1000 {
+        exact = exact.integrate(&angular_velocity, 0.0005);
+        first_order = first_order.integrate_first_order(&angular_velocity, 0.0005);
+    }
+    assert_relative_eq!(exact, turned, epsilon = 1e-4);
+    assert_relative_eq!(first_order, turned, epsilon = 1e-4);
+    assert_relative_eq!(first_order.dot(&first_order), 1.0, epsilon = 1e-6);
+
+    // A single large first order step falls short of the exact rotation.
+    let angle_of = |q: Quaternion| q.to_scaled_axis().length();
+    let step = Quaternion::identity().integrate_first_order(&angular_velocity, 0.5);
+    assert!(angle_of(step) < angle_of(Quaternion::identity().integrate(&angular_velocity, 0.5)));
+}
+
+#[test]
+fn angular_velocity() {
+    let from = Quaternion::from_euler(Degrees(10.0), Degrees(20.0), Degrees(-5.0));
+    let to = Quaternion::from_euler(Degrees(40.0), Degrees(-10.0), Degrees(15.0));
+    let velocity = from.angular_velocity_to(&to, 0.25);
+    assert_relative_eq!(from.integrate(&velocity, 0.25), to, epsilon = 1e-5);
+    // The negated target is the same orientation, so the velocity is the same.
+    let negated = Quaternion { x: -to.x, y: -to.y, z: -to.z, w: -to.w };
+    assert_relative_eq!(from.angular_velocity_to(&negated, 0.25), velocity, epsilon = 1e-5);
+
+    let derivative = from.derivative(&velocity);
+    assert_relative_eq!(from.angular_velocity_from_derivative(&derivative), velocity, epsilon = 1e-5);
+    // The derivative is perpendicular to a normalized quaternion, since its length does not change.
+    assert_relative_eq!(from.dot(&derivative), 0.0, epsilon = 1e-5);
+}
+
+#[test]
+fn from_rotation_matrix() {
+    use ezcgmath::matrix::{Matrix3x3, Matrix4x4};
+
+    // Cover every branch: a small rotation, then half turns where each diagonal element is the largest.
+    let rotations = [
+        Quaternion::from_euler(Degrees(20.0), Degrees(-35.0), Degrees(50.0)),
+        Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(170.0)),
+        Quaternion::from_axis_angle(&Vector3::unit_y(), Degrees(-160.0)),
+        Quaternion::from_axis_angle(&Vector3::unit_z(), Degrees(150.0)),
+    ];
+    for rotation in rotations.iter() {
+        let matrix = Matrix3x3::from(Matrix4x4::from(*rotation));
+        let found = Quaternion::from_rotation_matrix(&matrix);
+        assert_relative_eq!(found.dot(rotation).abs(), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(Matrix3x3::from(Matrix4x4::from(found)), matrix, epsilon = 1e-6);
+    }
+}
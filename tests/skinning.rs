@@ -0,0 +1,165 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::Degrees;
+use ezcgmath::dual_quaternion::DualQuaternion;
+use ezcgmath::matrix::Matrix4x4;
+use ezcgmath::quaternion::Quaternion;
+use ezcgmath::skinning::*;
+use ezcgmath::vector::Vector3;
+
+fn twist(angle: f32) -> Quaternion {
+    Quaternion::from_axis_angle(&Vector3::unit_x(), Degrees(angle))
+}
+
+#[test]
+fn normalize() {
+    let mut weights = [[2.0, 1.0, 1.0, 0.0], [0.0; 4], [0.5, 0.5, 0.0, 0.0]];
+    normalize_weights(&mut weights);
+    assert_eq!(weights, [[0.5, 0.25, 0.25, 0.0], [0.0; 4], [0.5, 0.5, 0.0, 0.0]]);
+}
+
+#[test]
+fn linear_single_bone() {
+    let bone = Matrix4x4::from(twist(90.0)) * Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    let positions = [Vector3::new(0.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 1.0)];
+    let normals = [Vector3::unit_y(), Vector3::unit_z()];
+    let mut skinned_positions = [Vector3::default(); 2];
+    let mut skinned_normals = [Vector3::default(); 2];
+    // The unused slots point at a bone that does not exist, which is fine as their weight is zero.
+    let indices = [[1, 99, 99, 99], [1, 0, 99, 99]];
+    let weights = [[1.0, 0.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]];
+    skin_linear(&positions, &normals, &indices, &weights, &[Matrix4x4::identity(), bone], &mut skinned_positions, &mut skinned_normals);
+    for i in 0..2 {
+        assert_relative_eq!(skinned_positions[i], bone.transform_point3(&positions[i]), epsilon = 1e-6);
+        assert_relative_eq!(skinned_normals[i], normals[i] * twist(90.0), epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn linear_blend() {
+    let palette = [
+        Matrix4x4::from_translation(&Vector3::new(2.0, 0.0, 0.0)),
+        Matrix4x4::from_translation(&Vector3::new(0.0, 4.0, 0.0)),
+        Matrix4x4::from_nonuniform_scale(&Vector3::new(1.0, 2.0, 1.0)),
+    ];
+    let positions = [Vector3::new(1.0, 1.0, 1.0)];
+    let mut skinned = [Vector3::default()];
+    skin_linear(&positions, &[], &[[0, 1, 2, 0]], &[[0.25, 0.25, 0.5, 0.0]], &palette, &mut skinned, &mut []);
+    // 0.25 * (3, 1, 1) + 0.25 * (1, 5, 1) + 0.5 * (1, 2, 1)
+    assert_relative_eq!(skinned[0], Vector3::new(1.5, 2.5, 1.0), epsilon = 1e-6);
+
+    // Normals stay perpendicular to the surface under non-uniform scale.
+    let normals = [Vector3::new(1.0, 1.0, 0.0)];
+    let mut skinned_normals = [Vector3::default()];
+    skin_linear(&positions, &normals, &[[2]], &[[1.0]], &palette, &mut skinned, &mut skinned_normals);
+    let mut expected = Vector3::new(2.0, 1.0, 0.0);
+    expected.normalize();
+    assert_relative_eq!(skinned_normals[0], expected, epsilon = 1e-6);
+}
+
+#[test]
+fn eight_influences() {
+    let palette: Vec<Matrix4x4> = (0..8).map(|i| Matrix4x4::from_translation(&Vector3::new(i as f32, 0.0, 0.0))).collect();
+    let dual_palette: Vec<DualQuaternion> = palette.iter().map(DualQuaternion::from_matrix).collect();
+    let mut weights = [[1.0; 8]];
+    normalize_weights(&mut weights);
+    let indices = [[0, 1, 2, 3, 4, 5, 6, 7]];
+    let positions = [Vector3::new(0.0, 1.0, 0.0)];
+    let mut linear = [Vector3::default()];
+    let mut dual = [Vector3::default()];
+    skin_linear(&positions, &[], &indices, &weights, &palette, &mut linear, &mut []);
+    skin_dual_quaternion(&positions, &[], &indices, &weights, &dual_palette, &mut dual, &mut []);
+    assert_relative_eq!(linear[0], Vector3::new(3.5, 1.0, 0.0), epsilon = 1e-6);
+    assert_relative_eq!(dual[0], Vector3::new(3.5, 1.0, 0.0), epsilon = 1e-6);
+}
+
+#[test]
+fn dual_quaternion_matches_linear_for_one_bone() {
+    let bone = DualQuaternion::from_rotation_translation(&Quaternion::from_euler(Degrees(20.0), Degrees(70.0), Degrees(-30.0)), &Vector3::new(1.0, -2.0, 0.5));
+    let positions = [Vector3::new(0.5, 1.0, -2.0), Vector3::new(-1.0, 0.0, 3.0)];
+    let normals = [Vector3::unit_x(), Vector3::unit_z()];
+    let indices = [[0, 0, 0, 0], [0, 0, 0, 0]];
+    let weights = [[1.0, 0.0, 0.0, 0.0], [0.25, 0.25, 0.25, 0.25]];
+    let mut linear = ([Vector3::default(); 2], [Vector3::default(); 2]);
+    let mut dual = ([Vector3::default(); 2], [Vector3::default(); 2]);
+    skin_linear(&positions, &normals, &indices, &weights, &[Matrix4x4::from(bone)], &mut linear.0, &mut linear.1);
+    skin_dual_quaternion(&positions, &normals, &indices, &weights, &[bone], &mut dual.0, &mut dual.1);
+    for i in 0..2 {
+        assert_relative_eq!(dual.0[i], linear.0[i], epsilon = 1e-5);
+        assert_relative_eq!(dual.1[i], linear.1[i], epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn candy_wrapper() {
+    // A vertex on the surface of an arm, halfway between a shoulder and a wrist twisted by 170 degrees.
+    let palette = [Matrix4x4::identity(), Matrix4x4::from(twist(170.0))];
+    let dual_palette = [DualQuaternion::identity(), DualQuaternion::from_rotation(&twist(170.0))];
+    let positions = [Vector3::new(5.0, 1.0, 0.0)];
+    let indices = [[0, 1, 0, 0]];
+    let weights = [[0.5, 0.5, 0.0, 0.0]];
+    let mut linear = [Vector3::default()];
+    let mut dual = [Vector3::default()];
+    skin_linear(&positions, &[], &indices, &weights, &palette, &mut linear, &mut []);
+    skin_dual_quaternion(&positions, &[], &indices, &weights, &dual_palette, &mut dual, &mut []);
+
+    // Linear blending collapses the arm towards its axis, dual quaternion blending keeps its radius.
+    let radius = |v: Vector3| (v.y * v.y + v.z * v.z).sqrt();
+    assert!(radius(linear[0]) < 0.1);
+    assert_relative_eq!(radius(dual[0]), 1.0, epsilon = 1e-5);
+    assert_relative_eq!(dual[0], positions[0] * twist(85.0), epsilon = 1e-5);
+}
+
+#[test]
+fn dual_quaternion_hemisphere() {
+    // Negating a dual quaternion describes the same transformation, and must not change the result.
+    let bone = DualQuaternion::from_rotation_translation(&twist(60.0), &Vector3::new(0.0, 1.0, 0.0));
+    let negated = DualQuaternion {
+        real: Quaternion { x: -bone.real.x, y: -bone.real.y, z: -bone.real.z, w: -bone.real.w },
+        dual: Quaternion { x: -bone.dual.x, y: -bone.dual.y, z: -bone.dual.z, w: -bone.dual.w },
+    };
+    let positions = [Vector3::new(1.0, 2.0, 3.0)];
+    let mut expected = [Vector3::default()];
+    let mut skinned = [Vector3::default()];
+    skin_dual_quaternion(&positions, &[], &[[0, 1]], &[[0.3, 0.7]], &[bone, bone], &mut expected, &mut []);
+    skin_dual_quaternion(&positions, &[], &[[0, 1]], &[[0.3, 0.7]], &[bone, negated], &mut skinned, &mut []);
+    assert_relative_eq!(skinned[0], expected[0], epsilon = 1e-5);
+    assert_relative_eq!(skinned[0], bone.transform_point3(&positions[0]), epsilon = 1e-5);
+}
+
+#[test]
+fn unweighted_vertex() {
+    let bone = Matrix4x4::from(twist(90.0)) * Matrix4x4::from_translation(&Vector3::new(1.0, 2.0, 3.0));
+    let positions = [Vector3::new(1.0, 2.0, 3.0)];
+    let normals = [Vector3::new(0.0, 2.0, 0.0)];
+    let indices = [[0, 0, 0, 0]];
+    let weights = [[0.0; 4]];
+    let mut linear = ([Vector3::default()], [Vector3::default()]);
+    let mut dual = ([Vector3::default()], [Vector3::default()]);
+    skin_linear(&positions, &normals, &indices, &weights, &[bone], &mut linear.0, &mut linear.1);
+    skin_dual_quaternion(&positions, &normals, &indices, &weights, &[DualQuaternion::from_matrix(&bone)], &mut dual.0, &mut dual.1);
+    for skinned in [linear, dual].iter() {
+        assert_eq!(skinned.0[0], positions[0]);
+        assert_eq!(skinned.1[0], Vector3::unit_y());
+    }
+}
+
+#[test]
+fn collapsed_bone() {
+    // A bone scaled to zero along one axis has no normal matrix, so its normals are transformed like directions.
+    let palette = [Matrix4x4::from_nonuniform_scale(&Vector3::new(1.0, 0.0, 1.0))];
+    let normals = [Vector3::new(1.0, 1.0, 0.0)];
+    let mut skinned = [Vector3::default()];
+    let mut skinned_normals = [Vector3::default()];
+    skin_linear(&[Vector3::new(1.0, 1.0, 1.0)], &normals, &[[0]], &[[1.0]], &palette, &mut skinned, &mut skinned_normals);
+    assert_eq!(skinned[0], Vector3::new(1.0, 0.0, 1.0));
+    assert_eq!(skinned_normals[0], Vector3::unit_x());
+}
+
+#[test]
+#[should_panic]
+fn mismatched_lengths() {
+    let mut skinned = [Vector3::default(); 2];
+    skin_linear(&[Vector3::default(); 2], &[], &[[0u16; 4]], &[[1.0, 0.0, 0.0, 0.0]], &[Matrix4x4::identity()], &mut skinned, &mut []);
+}
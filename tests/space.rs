@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::prelude::*;
+use ezcgmath::space::*;
+
+const LOCAL_TO_WORLD: Matrix4x4 = Matrix4x4 {
+    c00: 0.0, c10: 0.0, c20: -1.0, c30: 0.0,
+    c01: 0.0, c11: 1.0, c21: 0.0, c31: 0.0,
+    c02: 1.0, c12: 0.0, c22: 0.0, c32: 0.0,
+    c03: 1.0, c13: 2.0, c23: 3.0, c33: 1.0,
+};
+
+#[test]
+fn layout() {
+    assert_eq!(std::mem::size_of::<Vector3In<World>>(), std::mem::size_of::<Vector3>());
+    assert_eq!(std::mem::size_of::<Point3In<World>>(), std::mem::size_of::<Point3>());
+    assert_eq!(std::mem::size_of::<Matrix4x4Between<Local, World>>(), std::mem::size_of::<Matrix4x4>());
+}
+
+#[test]
+fn composition() {
+    let local_to_world = Matrix4x4Between::<Local, World>::new(LOCAL_TO_WORLD);
+    let world_to_view = Matrix4x4Between::<World, View>::new(Matrix4x4::from_translation(&Vector3::new(0.0, 0.0, 10.0)));
+    let local_to_view = local_to_world * world_to_view;
+    assert_eq!(local_to_view.matrix(), LOCAL_TO_WORLD * world_to_view.matrix());
+
+    let point = Point3In::<Local>::new(1.0, 1.0, 1.0);
+    let through_world = point * local_to_world * world_to_view;
+    let direct = point * local_to_view;
+    assert_relative_eq!(through_world.point(), direct.point());
+    assert_relative_eq!(direct.point(), Point3::new(2.0, 3.0, 12.0));
+}
+
+#[test]
+fn inverse() {
+    let local_to_world = Matrix4x4Between::<Local, World>::new(LOCAL_TO_WORLD);
+    let world_to_local: Matrix4x4Between<World, Local> = local_to_world.inverse();
+    let point = Point3In::<Local>::new(4.0, -2.0, 0.5);
+    assert_relative_eq!((point * local_to_world * world_to_local).point(), point.point(), epsilon = 1e-6);
+}
+
+#[test]
+fn directions() {
+    let local_to_world = Matrix4x4Between::<Local, World>::new(LOCAL_TO_WORLD);
+    let direction = Vector3In::<Local>::new(1.0, 0.0, 0.0);
+    assert_relative_eq!(local_to_world.transform_vector3(&direction).vector(), Vector3::new(0.0, 0.0, -1.0));
+    assert_relative_eq!(local_to_world.transform_normal(&direction).vector(), Vector3::new(0.0, 0.0, -1.0));
+
+    let a = Point3In::<World>::new(1.0, 2.0, 3.0);
+    let b = Point3In::<World>::new(2.0, 4.0, 6.0);
+    let offset: Vector3In<World> = b - a;
+    assert_eq!(offset.vector(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(a + offset, b);
+    assert_eq!(b - offset, a);
+    assert_eq!((offset + offset - offset * 2.0).vector(), Vector3::new(0.0, 0.0, 0.0));
+    assert_ulps_eq!(offset.length(), 14.0f32.sqrt());
+    assert_ulps_eq!(a.distance(&b), 14.0f32.sqrt());
+}
+
+#[test]
+fn products() {
+    let x = Vector3In::<World>::new(2.0, 0.0, 0.0);
+    let y = Vector3In::<World>::new(0.0, 3.0, 0.0);
+    assert_eq!(x.dot(&y), 0.0);
+    assert_eq!(x.dot(&x), 4.0);
+    assert_eq!(x.cross(&y).vector(), Vector3::new(0.0, 0.0, 6.0));
+
+    let mut normalized = x;
+    normalized.normalize();
+    assert_eq!(normalized.vector(), Vector3::unit_x());
+}
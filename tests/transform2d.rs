@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate approx;
+
+use ezcgmath::{Degrees, Error};
+use ezcgmath::matrix::Affine2D;
+use ezcgmath::transform2d::{DTransform2D, Transform2D};
+use ezcgmath::vector::Vector2;
+
+const A: Transform2D = Transform2D::new(Vector2::new(5.0, -2.0), Degrees(30.0), Vector2::new(2.0, 0.5));
+
+#[test]
+fn identity() {
+    assert_eq!(Transform2D::default(), Transform2D::identity());
+    assert_eq!(Transform2D::identity().to_matrix(), Affine2D::identity());
+}
+
+#[test]
+fn to_matrix() {
+    let expected = Affine2D::from_nonuniform_scale(&A.scale)
+        * Affine2D::from_rotation(A.rotation)
+        * Affine2D::from_translation(&A.translation);
+    assert_eq!(A.to_matrix(), expected);
+    assert_eq!(Affine2D::from(A), expected);
+
+    let v = Vector2::new(1.0, 1.0);
+    assert_eq!(A.transform_point2(&v), expected.transform_point2(&v));
+    assert_eq!(A.transform_vector2(&v), expected.transform_vector2(&v));
+}
+
+#[test]
+fn from_matrix() {
+    let decomposed = Transform2D::from_matrix(&A.to_matrix());
+    assert_relative_eq!(decomposed.translation, A.translation, epsilon = 1e-6);
+    assert_relative_eq!(decomposed.rotation, A.rotation, epsilon = 1e-4);
+    assert_relative_eq!(decomposed.scale, A.scale, epsilon = 1e-6);
+
+    let mirrored = Transform2D::new(Vector2::new(0.0, 1.0), Degrees(-120.0), Vector2::new(3.0, -1.0));
+    let decomposed = Transform2D::from_matrix(&mirrored.to_matrix());
+    assert_relative_eq!(decomposed.to_matrix(), mirrored.to_matrix(), epsilon = 1e-5);
+}
+
+#[test]
+fn try_from_matrix() {
+    assert_eq!(Transform2D::try_from_matrix(&A.to_matrix(), 1e-6), Ok(Transform2D::from_matrix(&A.to_matrix())));
+
+    let collapsed = Affine2D::from_nonuniform_scale(&Vector2::new(0.0, 2.0));
+    assert!(Transform2D::from_matrix(&collapsed).scale.y.is_nan());
+    assert_eq!(Transform2D::try_from_matrix(&collapsed, 1e-6), Err(Error::SingularMatrix));
+}
+
+#[test]
+fn precision_conversion() {
+    let double = DTransform2D::from(A);
+    assert_eq!(double.to_f32(), A);
+    assert_relative_eq!(double.to_matrix().to_f32(), A.to_matrix(), epsilon = 1e-6);
+}
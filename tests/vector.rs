@@ -2,6 +2,7 @@
 extern crate approx;
 
 mod vector2 {
+    use ezcgmath::Error;
     use ezcgmath::vector::Vector2;
 
     const A: Vector2 = Vector2::new(2.0, 4.0);
@@ -11,7 +12,7 @@ mod vector2 {
     fn add() {
         let result = Vector2::new(7.0, 14.0);
         assert_ulps_eq!(A + B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec += B;
         assert_ulps_eq!(vec, result);
     }
@@ -20,7 +21,7 @@ mod vector2 {
     fn subtract() {
         let result = Vector2::new(-3.0, -6.0);
         assert_ulps_eq!(A - B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec -= B;
         assert_ulps_eq!(vec, result);
     }
@@ -30,7 +31,7 @@ mod vector2 {
         let rhs = 5.0;
         let result = Vector2::new(10.0, 20.0);
         assert_ulps_eq!(A * rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec *= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -40,7 +41,7 @@ mod vector2 {
         let rhs = 5.0;
         let result = Vector2::new(0.4, 0.8);
         assert_ulps_eq!(A / rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec /= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -53,28 +54,44 @@ mod vector2 {
 
     #[test]
     fn length() {
-        assert_ulps_eq!(A.length(), (6.0 as f32).sqrt());
-        assert_ulps_eq!(B.length(), (15.0 as f32).sqrt());
+        assert_ulps_eq!(A.length(), 20.0_f32.sqrt());
+        assert_ulps_eq!(B.length(), 125.0_f32.sqrt());
+        assert_ulps_eq!(Vector2::new(3.0, -4.0).length(), 5.0);
     }
 
     #[test]
     fn normalize() {
-        let mut a = A.clone();
+        let mut a = A;
         a.normalize();
-        let a_len = (6.0 as f32).sqrt();
+        let a_len = 20.0_f32.sqrt();
         let a_result = Vector2::new(2.0 / a_len, 4.0 / a_len);
         assert_ulps_eq!(a, a_result);
 
-        let mut b = B.clone();
+        let mut b = B;
         b.normalize();
-        let b_len = (15.0 as f32).sqrt();
+        let b_len = 125.0_f32.sqrt();
         let b_result = Vector2::new(5.0 / b_len, 10.0 / b_len);
         assert_ulps_eq!(b, b_result);
     }
+
+    #[test]
+    fn try_normalize() {
+        let mut zero = Vector2::default();
+        assert_eq!(zero.try_normalize(1e-6), Err(Error::ZeroLength));
+        assert_eq!(zero, Vector2::default());
+        assert_eq!(Vector2::new(1e-7, 0.0).checked_normalize(1e-6), None);
+
+        let mut a = Vector2::new(-3.0, 1.0);
+        assert_eq!(a.try_normalize(1e-6), Ok(()));
+        assert_ulps_eq!(a.length(), 1.0);
+        assert_eq!(Vector2::new(3.0, 4.0).checked_normalize(1e-6), Some(Vector2::new(0.6, 0.8)));
+    }
 }
 
 mod vector3 {
+    use ezcgmath::Error;
     use ezcgmath::matrix::{Matrix3x3, Matrix4x4};
+    use ezcgmath::point::Point3;
     use ezcgmath::vector::{Vector3, Vector4};
 
     const A: Vector3 = Vector3::new(2.0, 4.0, 6.0);
@@ -84,7 +101,7 @@ mod vector3 {
     fn add() {
         let result = Vector3::new(7.0, 14.0, 21.0);
         assert_ulps_eq!(A + B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec += B;
         assert_ulps_eq!(vec, result);
     }
@@ -93,7 +110,7 @@ mod vector3 {
     fn subtract() {
         let result = Vector3::new(-3.0, -6.0, -9.0);
         assert_ulps_eq!(A - B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec -= B;
         assert_ulps_eq!(vec, result);
     }
@@ -103,7 +120,7 @@ mod vector3 {
         let rhs = 5.0;
         let result = Vector3::new(10.0, 20.0, 30.0);
         assert_ulps_eq!(A * rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec *= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -113,7 +130,7 @@ mod vector3 {
         let rhs = 5.0;
         let result = Vector3::new(0.4, 0.8, 1.2);
         assert_ulps_eq!(A / rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec /= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -142,23 +159,78 @@ mod vector3 {
 
     #[test]
     fn length() {
-        let a_len = ((A.x.powf(2.0) + A.y.powf(2.0) + A.z.powf(2.0)) as f32).sqrt();
-        let b_len = ((B.x.powf(2.0) + B.y.powf(2.0) + B.z.powf(2.0)) as f32).sqrt();
+        let a_len = (A.x.powf(2.0) + A.y.powf(2.0) + A.z.powf(2.0)).sqrt();
+        let b_len = (B.x.powf(2.0) + B.y.powf(2.0) + B.z.powf(2.0)).sqrt();
         assert_ulps_eq!(A.length(), a_len);
         assert_ulps_eq!(B.length(), b_len);
     }
 
     #[test]
     fn normalize() {
-        let mut a = A.clone();
+        let mut a = A;
         a.normalize();
         assert_ulps_eq!(a.length(), 1.0);
 
-        let mut b = B.clone();
+        let mut b = B;
         b.normalize();
         assert_ulps_eq!(b.length(), 1.0);
     }
 
+    #[test]
+    fn try_normalize() {
+        let mut zero = Vector3::default();
+        assert_eq!(zero.try_normalize(1e-6), Err(Error::ZeroLength));
+        assert_eq!(zero, Vector3::default());
+        assert_eq!(Vector3::new(1e-7, 0.0, 0.0).checked_normalize(1e-6), None);
+        assert_eq!(Vector3::new(f32::NAN, 0.0, 0.0).checked_normalize(1e-6), None);
+
+        let mut a = A;
+        assert_eq!(a.try_normalize(1e-6), Ok(()));
+        assert_ulps_eq!(a.length(), 1.0);
+        assert_eq!(B.checked_normalize(1e-6), Some(B / B.length()));
+    }
+
+    fn directions() -> Vec<Vector3> {
+        let mut result = vec![
+            Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z(),
+            -Vector3::unit_x(), -Vector3::unit_y(), -Vector3::unit_z(),
+            Vector3::new(0.0, 1e-4, -1.0), Vector3::new(1e-7, 0.0, -1.0), Vector3::new(0.0, 0.0, -0.0),
+        ];
+        for i in 0..40 {
+            let t = i as f32 * 0.37;
+            result.push(Vector3::new(t.sin() * (t * 1.7).cos(), t.cos(), t.sin() * (t * 1.7).sin()));
+        }
+        result.retain(|v| v.length() > 0.0);
+        for v in result.iter_mut() {
+            v.normalize();
+        }
+        result
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        for n in directions() {
+            let (a, b) = n.orthonormal_basis();
+            assert_relative_eq!(a.length(), 1.0, epsilon = 1e-5);
+            assert_relative_eq!(b.length(), 1.0, epsilon = 1e-5);
+            assert_relative_eq!(a.dot(&b), 0.0, epsilon = 1e-5);
+            assert_relative_eq!(a.dot(&n), 0.0, epsilon = 1e-5);
+            assert_relative_eq!(b.dot(&n), 0.0, epsilon = 1e-5);
+            assert_relative_eq!(a.cross(&b), n, epsilon = 1e-5);
+            assert!(Matrix3x3::from_basis(&a, &b, &n).is_rotation(1e-5));
+        }
+    }
+
+    #[test]
+    fn any_orthogonal() {
+        for n in directions() {
+            let scaled = n * 25.0;
+            let orthogonal = scaled.any_orthogonal();
+            assert_relative_eq!(orthogonal.length(), 1.0, epsilon = 1e-5);
+            assert_relative_eq!(orthogonal.dot(&n), 0.0, epsilon = 1e-5);
+        }
+    }
+
     #[test]
     fn multiply_matrix3x3() {
         let mut lhs = Vector3::new(2.0, 4.0, 6.0);
@@ -184,19 +256,27 @@ mod vector3 {
             c02: 9.0, c12: 10.0, c22: 11.0, c32: 12.0,
             c03: 13.0, c13: 14.0, c23: 15.0, c33: 16.0,
         };
-        let result = Vector3 {
-            x: 89.0 / 128.0, y: 102.0 / 128.0, z: 115.0 / 128.0
-        };
+        let result = Vector3::new(76.0, 88.0, 100.0);
         assert_ulps_eq!(lhs * rhs, result);
         lhs *= rhs;
         assert_ulps_eq!(lhs, result);
+
+        let point = Vector3::new(2.0, 4.0, 6.0);
+        let result = Vector3 {
+            x: 89.0 / 128.0, y: 102.0 / 128.0, z: 115.0 / 128.0
+        };
+        assert_ulps_eq!(rhs.transform_point3(&point), result);
+        assert_ulps_eq!(Vector3::from(Point3::from(point) * rhs), result);
     }
 
     #[test]
     fn translation() {
         let lhs = Vector3::new(0.0, 0.0, 0.0);
         let rhs = Matrix4x4::from_translation(&Vector3::new(10.0, 0.0, 0.0));
-        assert_ulps_eq!(lhs * rhs, Vector3::new(10.0, 0.0, 0.0));
+        // Vectors are directions, so only points are translated.
+        assert_ulps_eq!(lhs * rhs, Vector3::new(0.0, 0.0, 0.0));
+        assert_ulps_eq!(Point3::origin() * rhs, Point3::new(10.0, 0.0, 0.0));
+        assert_ulps_eq!(rhs.transform_point3(&lhs), Vector3::new(10.0, 0.0, 0.0));
     }
 
     #[test]
@@ -224,7 +304,7 @@ mod vector4 {
     fn add() {
         let result = Vector4::new(7.0, 14.0, 21.0, 28.0);
         assert_ulps_eq!(A + B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec += B;
         assert_ulps_eq!(vec, result);
     }
@@ -233,7 +313,7 @@ mod vector4 {
     fn subtract() {
         let result = Vector4::new(-3.0, -6.0, -9.0, -12.0);
         assert_ulps_eq!(A - B, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec -= B;
         assert_ulps_eq!(vec, result);
     }
@@ -243,7 +323,7 @@ mod vector4 {
         let rhs = 5.0;
         let result = Vector4::new(10.0, 20.0, 30.0, 40.0);
         assert_ulps_eq!(A * rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec *= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -253,7 +333,7 @@ mod vector4 {
         let rhs = 5.0;
         let result = Vector4::new(0.4, 0.8, 1.2, 1.6);
         assert_ulps_eq!(A / rhs, result);
-        let mut vec = A.clone();
+        let mut vec = A;
         vec /= rhs;
         assert_ulps_eq!(vec, result);
     }
@@ -290,3 +370,107 @@ mod vector4 {
         assert_ulps_eq!(lhs, result);
     }
 }
+
+mod dvector3 {
+    use ezcgmath::matrix::DMatrix4x4;
+    use ezcgmath::vector::{DVector3, Vector3};
+
+    #[test]
+    fn precision_far_from_origin() {
+        let a = DVector3::new(6_371_000.0, 0.0, 0.0);
+        let b = DVector3::new(6_371_000.001, 0.0, 0.0);
+        assert_relative_eq!((b - a).x, 0.001, epsilon = 1e-9);
+
+        let translated = DMatrix4x4::from_translation(&a).transform_point3(&DVector3::new(0.25, 0.0, 0.0));
+        assert_eq!(translated.x, 6_371_000.25);
+    }
+
+    #[test]
+    fn precision_conversion() {
+        let single = Vector3::new(0.1, 0.2, 0.3);
+        let double = single.to_f64();
+        assert_eq!(double, DVector3::new(0.1_f32 as f64, 0.2_f32 as f64, 0.3_f32 as f64));
+        assert_eq!(DVector3::from(single), double);
+        assert_eq!(double.to_f32(), single);
+        assert_eq!(DVector3::new(0.1, 0.2, 0.3).to_f32(), single);
+    }
+}
+
+mod ivector2 {
+    use ezcgmath::vector::{IVector2, IVector3, Vector2};
+
+    const A: IVector2 = IVector2::new(2, -4);
+    const B: IVector2 = IVector2::new(-5, 10);
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(A + B, IVector2::new(-3, 6));
+        assert_eq!(A - B, IVector2::new(7, -14));
+        assert_eq!(A * 3, IVector2::new(6, -12));
+        assert_eq!(B / 5, IVector2::new(-1, 2));
+        assert_eq!(-A, IVector2::new(-2, 4));
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(A.min(&B), IVector2::new(-5, -4));
+        assert_eq!(A.max(&B), IVector2::new(2, 10));
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(A.manhattan_distance(&B), 21);
+        assert_eq!(A.chebyshev_distance(&B), 14);
+        assert_eq!(IVector2::new(i32::MIN, 0).manhattan_distance(&IVector2::new(i32::MAX, 0)), u32::MAX as u64);
+
+        let min = IVector3::new(i32::MIN, 0, 0);
+        let max = IVector3::new(i32::MAX, i32::MAX, 0);
+        assert_eq!(min.manhattan_distance(&max), u32::MAX as u64 + i32::MAX as u64);
+        assert_eq!(min.chebyshev_distance(&max), u32::MAX);
+    }
+
+    #[test]
+    fn float_conversion() {
+        let vec = Vector2::new(-1.5, 2.5);
+        assert_eq!(IVector2::from_floor(&vec), IVector2::new(-2, 2));
+        assert_eq!(IVector2::from_round(&vec), IVector2::new(-2, 3));
+        assert_eq!(IVector2::from_truncate(&vec), IVector2::new(-1, 2));
+        assert_eq!(Vector2::from(A), Vector2::new(2.0, -4.0));
+    }
+}
+
+mod uvector3 {
+    use ezcgmath::vector::{UVector3, UVector4, Vector3};
+
+    const A: UVector3 = UVector3::new(2, 4, 6);
+    const B: UVector3 = UVector3::new(5, 1, 6);
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(A + B, UVector3::new(7, 5, 12));
+        assert_eq!(A * 3 - B, UVector3::new(1, 11, 12));
+        assert_eq!(A / 2, UVector3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(A.min(&B), UVector3::new(2, 1, 6));
+        assert_eq!(A.max(&B), UVector3::new(5, 4, 6));
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(A.manhattan_distance(&B), 6);
+        assert_eq!(A.chebyshev_distance(&B), 3);
+        assert_eq!(UVector4::new(u32::MAX, u32::MAX, u32::MAX, u32::MAX).manhattan_distance(&UVector4::default()), 4 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn float_conversion() {
+        let vec = Vector3::new(-1.5, 2.5, 7.9);
+        assert_eq!(UVector3::from_floor(&vec), UVector3::new(0, 2, 7));
+        assert_eq!(UVector3::from_round(&vec), UVector3::new(0, 3, 8));
+        assert_eq!(UVector3::from_truncate(&vec), UVector3::new(0, 2, 7));
+        assert_eq!(Vector3::from(A), Vector3::new(2.0, 4.0, 6.0));
+    }
+}